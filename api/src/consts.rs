@@ -41,21 +41,56 @@ pub const ONE_WEEK_SLOTS: u64 = 7 * ONE_DAY_SLOTS;
 /// The number of slots for breather between rounds.
 pub const INTERMISSION_SLOTS: u64 = 35;
 
+/// The number of slots after the end slot that may be sampled for randomness,
+/// if the end slot hash is no longer available in the slot hashes sysvar.
+pub const SLOT_HASH_FALLBACK_SLOTS: u64 = ONE_MINUTE_SLOTS;
+
+/// The number of slots after the end slot to wait for the randomness authority to reveal,
+/// before a commit-reveal round without a reveal may be reset and refunded.
+pub const REVEAL_WINDOW_SLOTS: u64 = ONE_MINUTE_SLOTS;
+
 /// The maximum token supply (5 million).
 pub const MAX_SUPPLY: u64 = ONE_ORE * 5_000_000;
 
+/// The seed of the allowlist account PDA.
+pub const ALLOWLIST: &[u8] = b"allowlist";
+
 /// The seed of the automation account PDA.
 pub const AUTOMATION: &[u8] = b"automation";
 
+/// The seed of the bonus account PDA.
+pub const BONUS: &[u8] = b"bonus";
+
 /// The seed of the board account PDA.
 pub const BOARD: &[u8] = b"board";
 
+/// The seed of the collection account PDA.
+pub const COLLECTION: &[u8] = b"collection";
+
 /// The seed of the config account PDA.
 pub const CONFIG: &[u8] = b"config";
 
+/// The seed of the fee split account PDA.
+pub const FEE_SPLIT: &[u8] = b"fee_split";
+
+/// The seed of the history account PDA.
+pub const HISTORY: &[u8] = b"history";
+
 /// The seed of the miner account PDA.
 pub const MINER: &[u8] = b"miner";
 
+/// The seed of the pool account PDA.
+pub const POOL: &[u8] = b"pool";
+
+/// The seed of the pool member account PDA.
+pub const POOL_MEMBER: &[u8] = b"pool_member";
+
+/// The seed of the season account PDA.
+pub const SEASON: &[u8] = b"season";
+
+/// The seed of the season miner account PDA.
+pub const SEASON_MINER: &[u8] = b"season_miner";
+
 /// The seed of the seeker account PDA.
 pub const SEEKER: &[u8] = b"seeker";
 
@@ -65,6 +100,9 @@ pub const SQUARE: &[u8] = b"square";
 /// The seed of the stake account PDA.
 pub const STAKE: &[u8] = b"stake";
 
+/// The seed of the referrer account PDA.
+pub const REFERRER: &[u8] = b"referrer";
+
 /// The seed of the round account PDA.
 pub const ROUND: &[u8] = b"round";
 
@@ -81,6 +119,12 @@ pub const CONFIG_ADDRESS: Pubkey =
 /// The address of the mint account.
 pub const MINT_ADDRESS: Pubkey = pubkey!("oreoU2P8bN6jkk3jbaiVxYnG1dCXcYxwhwyK9jSybcp");
 
+/// The mint authority of Seeker genesis tokens.
+pub const SEEKER_MINT_AUTHORITY: Pubkey = pubkey!("GT2zuHVaZQYZSyQMgJPLzvkmyztfyXg2NJunqFp4p3A4");
+
+/// The metadata address of Seeker genesis tokens.
+pub const SEEKER_METADATA_ADDRESS: Pubkey = pubkey!("GT22s89nU4iWFkNXj1Bw6uYhJJWDRPpShHt4Bk8f99Te");
+
 /// The address of the sol mint account.
 pub const SOL_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
 
@@ -97,8 +141,52 @@ pub const TREASURY_BUMP: u8 = ed25519::derive_program_address(&[TREASURY], &PROG
 /// Denominator for fee calculations.
 pub const DENOMINATOR_BPS: u64 = 10_000;
 
+/// The lock duration of each staking tier, in seconds.
+pub const LOCK_TIER_DURATIONS: [i64; 4] = [0, 30 * ONE_DAY, 90 * ONE_DAY, 365 * ONE_DAY];
+
+/// The reward weight multiplier of each staking tier, in basis points.
+pub const LOCK_TIER_MULTIPLIERS_BPS: [u64; 4] = [10_000, 12_500, 15_000, 20_000];
+
+/// The maximum extra reward weight multiplier for Seeker stakers, in basis points.
+pub const MAX_SEEKER_MULTIPLIER_BPS: u64 = 20_000;
+
+/// The maximum cooldown between unstaking and withdrawing ORE, in seconds.
+pub const MAX_UNSTAKE_COOLDOWN: i64 = 30 * ONE_DAY;
+
+/// The maximum number of squares on the board. Account layouts are sized to hold this many.
+pub const MAX_SQUARES: usize = 64;
+
+/// The number of squares on the board, unless configured otherwise.
+/// Accounts laid out before the board size was configurable hold this many squares.
+pub const DEFAULT_SQUARES: usize = 25;
+
+/// The maximum number of squares that can win a round.
+pub const MAX_WINNING_SQUARES: u64 = 12;
+
+/// The maximum fee a pool operator may charge on pool rewards, in basis points.
+pub const MAX_POOL_FEE_BPS: u64 = 2_000;
+
+/// The maximum number of recipients in the fee split.
+pub const MAX_FEE_RECIPIENTS: usize = 8;
+
+/// The number of recent rounds kept in the history account.
+pub const HISTORY_SIZE: usize = 64;
+
+/// The number of miners ranked on a season leaderboard.
+pub const SEASON_LEADERBOARD_SIZE: usize = 10;
+
+/// The share of the season reward pool paid to each rank, in basis points.
+pub const SEASON_REWARD_WEIGHTS_BPS: [u64; SEASON_LEADERBOARD_SIZE] =
+    [2_500, 1_800, 1_300, 1_000, 800, 700, 600, 500, 450, 350];
+
 /// The address of the boost reserve token account.
 pub const BOOST_RESERVE_TOKEN: Pubkey = pubkey!("Gce36ZUsBDJsoLrfCBxUB5Sfq2DsGunofStvxFx6rBiD");
 
 /// The fee paid to bots if they checkpoint a user.
 pub const CHECKPOINT_FEE: u64 = 10_000; // 0.00001 SOL
+
+/// The maximum amount of SOL wrapped per call, unless configured otherwise.
+pub const DEFAULT_MAX_WRAP: u64 = 10_000_000_000; // 10 SOL
+
+/// The maximum bounty paid to the caller of a reset or close.
+pub const MAX_CRANK_BOUNTY: u64 = 10_000_000; // 0.01 SOL
@@ -8,6 +8,96 @@ pub enum OreError {
 
     #[error("Not authorized")]
     NotAuthorized = 1,
+
+    #[error("Reveal does not match commitment")]
+    InvalidReveal = 2,
+
+    #[error("Bounty exceeds maximum")]
+    BountyTooLarge = 3,
+
+    #[error("Basis points exceed denominator")]
+    InvalidBps = 4,
+
+    #[error("Stake is locked")]
+    StakeLocked = 5,
+
+    #[error("Invalid lock tier")]
+    InvalidLockTier = 6,
+
+    #[error("Withdrawal is still cooling down")]
+    CooldownNotElapsed = 7,
+
+    #[error("Cooldown exceeds maximum")]
+    CooldownTooLong = 8,
+
+    #[error("Miner has not checkpointed")]
+    NotCheckpointed = 9,
+
+    #[error("Account has outstanding balances or rewards")]
+    AccountNotEmpty = 10,
+
+    #[error("Pool fee exceeds maximum")]
+    PoolFeeTooHigh = 11,
+
+    #[error("Pool balance too low")]
+    InsufficientPoolBalance = 12,
+
+    #[error("Deploy exceeds the per-round limit")]
+    DeployExceedsRoundLimit = 13,
+
+    #[error("Deploy exceeds the per-square limit")]
+    DeployExceedsSquareLimit = 14,
+
+    #[error("Invalid deploy limits")]
+    InvalidDeployLimits = 15,
+
+    #[error("Authority is not on the allowlist")]
+    NotAllowlisted = 16,
+
+    #[error("Token is not from a registered collection")]
+    IneligibleToken = 17,
+
+    #[error("Collection claims are disabled")]
+    ClaimsDisabled = 18,
+
+    #[error("Perk already claimed")]
+    PerkAlreadyClaimed = 19,
+
+    #[error("Round has already been reset")]
+    RoundAlreadyReset = 20,
+
+    #[error("Invalid season rounds")]
+    InvalidSeason = 21,
+
+    #[error("Season is in progress")]
+    SeasonInProgress = 22,
+
+    #[error("Season has ended")]
+    SeasonEnded = 23,
+
+    #[error("Miner is not ranked in the season")]
+    NotRanked = 24,
+
+    #[error("Season rewards already claimed")]
+    SeasonAlreadyClaimed = 25,
+
+    #[error("Invalid number of winning squares")]
+    InvalidWinningSquares = 26,
+
+    #[error("Invalid board size")]
+    InvalidBoardSize = 27,
+
+    #[error("Invalid fee split")]
+    InvalidFeeSplit = 28,
+
+    #[error("Pool capital is deployed")]
+    PoolCapitalDeployed = 29,
+
+    #[error("Invalid wrap cap")]
+    InvalidWrapCap = 30,
+
+    #[error("Treasury reserve exceeds the treasury balance")]
+    InvalidTreasuryReserve = 31,
 }
 
 error!(OreError);
@@ -4,6 +4,17 @@ pub enum OreEvent {
     Reset = 0,
     Bury = 1,
     Motherlode = 2,
+    Bounty = 3,
+    Referral = 4,
+    Wrap = 5,
+}
+
+/// The crank instruction a bounty was paid for.
+#[repr(u64)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BountyKind {
+    Reset = 0,
+    Close = 1,
 }
 
 #[repr(C)]
@@ -21,13 +32,13 @@ pub struct ResetEvent {
     /// The end slot of the next block.
     pub end_slot: u64,
 
-    /// The winning square of the round.
+    /// The primary winning square of the round.
     pub winning_square: u64,
 
     /// The top miner of the round.
     pub top_miner: Pubkey,
 
-    /// The number of miners on the winning square.
+    /// The number of miners on the winning squares.
     pub num_winners: u64,
 
     /// The amount of ORE payout for the motherlode.
@@ -47,6 +58,15 @@ pub struct ResetEvent {
 
     /// The timestamp of the event.
     pub ts: i64,
+
+    /// The amount of sponsored SOL paid out for the round, or rolled into the next round if no one won.
+    pub bonus_sol: u64,
+
+    /// The amount of sponsored ORE paid out for the round, or rolled into the next round if no one won.
+    pub bonus_ore: u64,
+
+    /// A bitmask of the winning squares of the round (0 if the round was refunded).
+    pub winning_mask: u64,
 }
 
 #[repr(C)]
@@ -71,6 +91,25 @@ pub struct BuryEvent {
     pub ts: i64,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct WrapEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The amount of SOL requested to be wrapped.
+    pub requested: u64,
+
+    /// The amount of SOL wrapped.
+    pub amount: u64,
+
+    /// The treasury SOL balance remaining after the wrap.
+    pub treasury_balance: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
 pub struct MotherlodeEvent {
@@ -90,6 +129,53 @@ pub struct MotherlodeEvent {
     pub ts: i64,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct BountyEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The round the crank was called for.
+    pub round_id: u64,
+
+    /// The crank instruction the bounty was paid for.
+    pub kind: u64,
+
+    /// The address that received the bounty.
+    pub authority: Pubkey,
+
+    /// The amount of SOL paid.
+    pub amount: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ReferralEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The round the referral fee was earned in.
+    pub round_id: u64,
+
+    /// The referrer credited with the fee.
+    pub referrer: Pubkey,
+
+    /// The referred miner.
+    pub miner: Pubkey,
+
+    /// The amount of SOL credited to the referrer.
+    pub amount: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
 event!(ResetEvent);
 event!(BuryEvent);
 event!(MotherlodeEvent);
+event!(BountyEvent);
+event!(ReferralEvent);
+event!(WrapEvent);
@@ -1,5 +1,7 @@
 use steel::*;
 
+use crate::consts::MAX_FEE_RECIPIENTS;
+
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, TryFromPrimitive)]
 pub enum OreInstruction {
@@ -8,25 +10,77 @@ pub enum OreInstruction {
     Checkpoint = 2,
     ClaimSOL = 3,
     ClaimORE = 4,
+    ClaimOreToStake = 29,
+    TransferMiner = 31,
+    CloseMiner = 33,
+    SetClaimDelegate = 37,
+    ClaimReferral = 44,
     Close = 5,
     Deploy = 6,
     Initialize = 7,
     Log = 8,
     Reset = 9,
+    Sponsor = 52,
+    DistributeFees = 59,
 
     // Staker
     Deposit = 10,
     Withdraw = 11,
     ClaimYield = 12,
+    ClaimYieldSol = 22,
+    Unstake = 25,
+    CompoundYield = 27,
+    SetAutoCompound = 28,
+    TransferStake = 32,
+    CloseStake = 34,
+    RefreshStake = 61,
 
     // Admin
     Bury = 13,
     Wrap = 14,
     SetAdmin = 15,
     SetFeeCollector = 16,
+    SetEntropySource = 19,
+    SetCrankBounty = 20,
+    SetCloseDestination = 21,
+    SetStakeSolYield = 23,
+    SetSeekerMultiplier = 24,
+    SetUnstakeCooldown = 26,
+    SetStakeClaimDiscount = 30,
+    SetReferralFee = 43,
+    SetDeployLimits = 45,
+    AddToAllowlist = 46,
+    RemoveFromAllowlist = 47,
+    SetAllowlistEnabled = 48,
+    SetCollection = 49,
+    RemoveCollection = 50,
+    SetSeekerActivation = 51,
+    SetWinningSquares = 56,
+    SetBoardSize = 57,
+    SetFeeSplit = 58,
+    SetWrapPolicy = 60,
 
     // Seeker
     ClaimSeeker = 17,
+
+    // Entropy
+    Commit = 18,
+
+    // Pool
+    CreatePool = 38,
+    JoinPool = 39,
+    LeavePool = 40,
+    ClaimPool = 41,
+    HarvestPool = 42,
+
+    // Season
+    CreateSeason = 53,
+    FundSeason = 54,
+    ClaimSeason = 55,
+
+    // Migration
+    Migrate = 35,
+    MigrateStaker = 36,
 }
 
 #[repr(C)]
@@ -47,11 +101,20 @@ pub struct ClaimSOL {}
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct ClaimORE {}
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ClaimOreToStake {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct TransferMiner {}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Deploy {
     pub amount: [u8; 8],
-    pub squares: [u8; 4],
+    pub squares: [u8; 8],
+    pub referrer: [u8; 32],
 }
 
 #[repr(C)]
@@ -64,7 +127,9 @@ pub struct Log {}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Reset {}
+pub struct Reset {
+    pub reveal: [u8; 32],
+}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
@@ -99,6 +164,165 @@ pub struct SetFeeCollector {
     pub fee_collector: [u8; 32],
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetEntropySource {
+    pub entropy_source: [u8; 8],
+    pub randomness_authority: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetCrankBounty {
+    pub reset_bounty: [u8; 8],
+    pub close_bounty: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetCloseDestination {
+    pub close_destination: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetStakeSolYield {
+    pub admin_fee_bps: [u8; 8],
+    pub vault_bps: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetSeekerMultiplier {
+    pub seeker_multiplier_bps: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetUnstakeCooldown {
+    pub unstake_cooldown: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetStakeClaimDiscount {
+    pub stake_claim_fee_discount_bps: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetReferralFee {
+    pub referral_fee_bps: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetDeployLimits {
+    pub min_deploy_amount: [u8; 8],
+    pub max_deploy_per_round: [u8; 8],
+    pub max_deploy_per_square: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct AddToAllowlist {
+    pub authority: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct RemoveFromAllowlist {
+    pub authority: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetAllowlistEnabled {
+    pub enabled: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetCollection {
+    pub mint_authority: [u8; 32],
+    pub metadata_address: [u8; 32],
+    pub token_program: [u8; 32],
+    pub perk: [u8; 8],
+    pub perk_bps: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct RemoveCollection {
+    pub mint_authority: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetSeekerActivation {
+    pub enabled: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetWinningSquares {
+    pub winning_squares: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetBoardSize {
+    pub squares: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetWrapPolicy {
+    pub max_wrap: [u8; 8],
+    pub treasury_reserve: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetFeeSplit {
+    pub num_recipients: [u8; 8],
+    pub addresses: [[u8; 32]; MAX_FEE_RECIPIENTS],
+    pub bps: [[u8; 8]; MAX_FEE_RECIPIENTS],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Sponsor {
+    pub round_id: [u8; 8],
+    pub amount_sol: [u8; 8],
+    pub amount_ore: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct DistributeFees {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CreateSeason {
+    pub start_round: [u8; 8],
+    pub end_round: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct FundSeason {
+    pub season_id: [u8; 8],
+    pub amount_sol: [u8; 8],
+    pub amount_ore: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ClaimSeason {
+    pub season_id: [u8; 8],
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct SetFeeRate {
@@ -107,18 +331,22 @@ pub struct SetFeeRate {
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Wrap {}
+pub struct Wrap {
+    pub amount: [u8; 8],
+}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Bury {
     pub min_amount_out: [u8; 8],
+    pub wrap_amount: [u8; 8],
 }
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Deposit {
     pub amount: [u8; 8],
+    pub lock_tier: [u8; 8],
 }
 
 #[repr(C)]
@@ -127,12 +355,49 @@ pub struct Withdraw {
     pub amount: [u8; 8],
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Unstake {
+    pub amount: [u8; 8],
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct ClaimYield {
     pub amount: [u8; 8],
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CompoundYield {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetClaimDelegate {
+    pub claim_delegate: [u8; 32],
+    pub payout_recipient: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ClaimReferral {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetAutoCompound {
+    pub auto_compound: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct TransferStake {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ClaimYieldSol {
+    pub amount: [u8; 8],
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct ClaimSeeker {}
@@ -145,11 +410,64 @@ pub struct Checkpoint {}
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Close {}
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CloseMiner {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CloseStake {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct RefreshStake {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CreatePool {
+    pub fee_bps: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct JoinPool {
+    pub amount: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct LeavePool {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ClaimPool {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct HarvestPool {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Migrate {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct MigrateStaker {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Commit {
+    pub commit: [u8; 32],
+}
+
 instruction!(OreInstruction, Automate);
 instruction!(OreInstruction, Close);
 instruction!(OreInstruction, Checkpoint);
 instruction!(OreInstruction, ClaimSOL);
 instruction!(OreInstruction, ClaimORE);
+instruction!(OreInstruction, ClaimOreToStake);
+instruction!(OreInstruction, TransferMiner);
+instruction!(OreInstruction, CloseMiner);
 instruction!(OreInstruction, Deploy);
 instruction!(OreInstruction, Initialize);
 instruction!(OreInstruction, Log);
@@ -160,5 +478,46 @@ instruction!(OreInstruction, SetAdmin);
 instruction!(OreInstruction, SetFeeCollector);
 instruction!(OreInstruction, Deposit);
 instruction!(OreInstruction, Withdraw);
+instruction!(OreInstruction, Unstake);
 instruction!(OreInstruction, ClaimYield);
+instruction!(OreInstruction, ClaimYieldSol);
+instruction!(OreInstruction, CompoundYield);
+instruction!(OreInstruction, SetAutoCompound);
+instruction!(OreInstruction, SetClaimDelegate);
+instruction!(OreInstruction, ClaimReferral);
+instruction!(OreInstruction, TransferStake);
+instruction!(OreInstruction, CloseStake);
+instruction!(OreInstruction, RefreshStake);
 instruction!(OreInstruction, ClaimSeeker);
+instruction!(OreInstruction, Commit);
+instruction!(OreInstruction, CreatePool);
+instruction!(OreInstruction, JoinPool);
+instruction!(OreInstruction, LeavePool);
+instruction!(OreInstruction, ClaimPool);
+instruction!(OreInstruction, HarvestPool);
+instruction!(OreInstruction, Migrate);
+instruction!(OreInstruction, MigrateStaker);
+instruction!(OreInstruction, SetEntropySource);
+instruction!(OreInstruction, SetCrankBounty);
+instruction!(OreInstruction, SetCloseDestination);
+instruction!(OreInstruction, SetStakeSolYield);
+instruction!(OreInstruction, SetSeekerMultiplier);
+instruction!(OreInstruction, SetUnstakeCooldown);
+instruction!(OreInstruction, SetStakeClaimDiscount);
+instruction!(OreInstruction, SetReferralFee);
+instruction!(OreInstruction, SetDeployLimits);
+instruction!(OreInstruction, AddToAllowlist);
+instruction!(OreInstruction, RemoveFromAllowlist);
+instruction!(OreInstruction, SetAllowlistEnabled);
+instruction!(OreInstruction, SetCollection);
+instruction!(OreInstruction, RemoveCollection);
+instruction!(OreInstruction, SetSeekerActivation);
+instruction!(OreInstruction, SetWinningSquares);
+instruction!(OreInstruction, SetBoardSize);
+instruction!(OreInstruction, SetFeeSplit);
+instruction!(OreInstruction, SetWrapPolicy);
+instruction!(OreInstruction, Sponsor);
+instruction!(OreInstruction, DistributeFees);
+instruction!(OreInstruction, CreateSeason);
+instruction!(OreInstruction, FundSeason);
+instruction!(OreInstruction, ClaimSeason);
@@ -6,7 +6,7 @@ use spl_associated_token_account::{
 use steel::*;
 
 use crate::{
-    consts::{BOARD, MINT_ADDRESS, SOL_MINT, TREASURY_ADDRESS},
+    consts::{BOARD, MAX_FEE_RECIPIENTS, MAX_SQUARES, MINT_ADDRESS, SOL_MINT, TREASURY_ADDRESS},
     instruction::*,
     state::*,
 };
@@ -25,7 +25,7 @@ pub fn program_log(accounts: &[AccountInfo], msg: &[u8]) -> Result<(), ProgramEr
     invoke_signed(&log(*accounts[0].key, msg), accounts, &crate::ID, &[BOARD])
 }
 
-// let [signer_info, automation_info, executor_info, miner_info, system_program] = accounts else {
+// let [signer_info, allowlist_info, automation_info, config_info, executor_info, miner_info, system_program] =
 
 pub fn automate(
     signer: Pubkey,
@@ -36,13 +36,17 @@ pub fn automate(
     mask: u64,
     strategy: u8,
 ) -> Instruction {
+    let allowlist_address = allowlist_pda(signer).0;
     let automation_address = automation_pda(signer).0;
+    let config_address = config_pda().0;
     let miner_address = miner_pda(signer).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(allowlist_address, false),
             AccountMeta::new(automation_address, false),
+            AccountMeta::new_readonly(config_address, false),
             AccountMeta::new(executor, false),
             AccountMeta::new(miner_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
@@ -63,6 +67,7 @@ pub fn automate(
 pub fn initialize(signer: Pubkey) -> Instruction {
     let config_address = config_pda().0;
     let board_address = board_pda().0;
+    let history_address = history_pda().0;
     let mint_address = MINT_ADDRESS;
     let treasury_address = TREASURY_ADDRESS;
     let treasury_tokens_address = treasury_tokens_address();
@@ -72,6 +77,7 @@ pub fn initialize(signer: Pubkey) -> Instruction {
             AccountMeta::new(signer, true),
             AccountMeta::new(board_address, false),
             AccountMeta::new(config_address, false),
+            AccountMeta::new(history_address, false),
             AccountMeta::new(mint_address, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new(treasury_tokens_address, false),
@@ -83,32 +89,36 @@ pub fn initialize(signer: Pubkey) -> Instruction {
     }
 }
 
-pub fn claim_sol(signer: Pubkey) -> Instruction {
-    let miner_address = miner_pda(signer).0;
+// let [signer_info, miner_info, recipient_info, system_program] =
+
+pub fn claim_sol(signer: Pubkey, authority: Pubkey, recipient: Pubkey) -> Instruction {
+    let miner_address = miner_pda(authority).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(miner_address, false),
+            AccountMeta::new(recipient, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
         data: ClaimSOL {}.to_bytes(),
     }
 }
 
-// let [signer_info, miner_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+// let [signer_info, miner_info, mint_info, owner_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
 
-pub fn claim_ore(signer: Pubkey) -> Instruction {
-    let miner_address = miner_pda(signer).0;
+pub fn claim_ore(signer: Pubkey, authority: Pubkey, recipient: Pubkey) -> Instruction {
+    let miner_address = miner_pda(authority).0;
     let treasury_address = treasury_pda().0;
     let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
-    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let recipient_address = get_associated_token_address(&recipient, &MINT_ADDRESS);
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(miner_address, false),
             AccountMeta::new(MINT_ADDRESS, false),
+            AccountMeta::new_readonly(recipient, false),
             AccountMeta::new(recipient_address, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new(treasury_tokens_address, false),
@@ -120,24 +130,60 @@ pub fn claim_ore(signer: Pubkey) -> Instruction {
     }
 }
 
-// let [signer_info, authority_info, automation_info, board_info, miner_info, round_info, system_program] =
+// let [signer_info, allowlist_info, config_info, miner_info, mint_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+
+pub fn claim_ore_to_stake(signer: Pubkey) -> Instruction {
+    let allowlist_address = allowlist_pda(signer).0;
+    let config_address = config_pda().0;
+    let miner_address = miner_pda(signer).0;
+    let stake_address = stake_pda(signer).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
+    let treasury_address = treasury_pda().0;
+    let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(allowlist_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(MINT_ADDRESS, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: ClaimOreToStake {}.to_bytes(),
+    }
+}
+
+// let [signer_info, authority_info, allowlist_info, automation_info, board_info, config_info, miner_info, referrer_info, round_info, season_miner_info, system_program] =
 
 pub fn deploy(
     signer: Pubkey,
     authority: Pubkey,
     amount: u64,
     round_id: u64,
-    squares: [bool; 25],
+    season_id: u64,
+    squares: &[bool],
+    referrer: Pubkey,
 ) -> Instruction {
+    let allowlist_address = allowlist_pda(authority).0;
     let automation_address = automation_pda(authority).0;
     let board_address = board_pda().0;
+    let config_address = config_pda().0;
     let miner_address = miner_pda(authority).0;
+    let referrer_address = referrer_pda(referrer).0;
     let round_address = round_pda(round_id).0;
+    let season_miner_address = season_miner_pda(season_id, authority).0;
 
-    // Convert array of 25 booleans into a 32-bit mask where each bit represents whether
+    // Convert array of booleans into a 64-bit mask where each bit represents whether
     // that square index is selected (1) or not (0)
-    let mut mask: u32 = 0;
-    for (i, &square) in squares.iter().enumerate() {
+    let mut mask: u64 = 0;
+    for (i, &square) in squares.iter().take(MAX_SQUARES).enumerate() {
         if square {
             mask |= 1 << i;
         }
@@ -148,15 +194,20 @@ pub fn deploy(
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(authority, false),
+            AccountMeta::new_readonly(allowlist_address, false),
             AccountMeta::new(automation_address, false),
             AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(config_address, false),
             AccountMeta::new(miner_address, false),
+            AccountMeta::new(referrer_address, false),
             AccountMeta::new(round_address, false),
+            AccountMeta::new(season_miner_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
         data: Deploy {
             amount: amount.to_le_bytes(),
             squares: mask.to_le_bytes(),
+            referrer: referrer.to_bytes(),
         }
         .to_bytes(),
     }
@@ -179,7 +230,7 @@ const PROTOCOL_TOKEN_FEE: Pubkey = pubkey!("6kzYo2LMo2q2bkLAD8ienoG5NC1MkNXNTfm8
 
 // let [pool, user_source_token, user_destination_token, a_vault, b_vault, a_token_vault, b_token_vault, a_vault_lp_mint, b_vault_lp_mint, a_vault_lp, b_vault_lp, protocol_token_fee, user_key, vault_program, token_program] =
 
-pub fn bury(signer: Pubkey, min_amount_out: u64) -> Instruction {
+pub fn bury(signer: Pubkey, min_amount_out: u64, wrap_amount: u64) -> Instruction {
     let board_address = board_pda().0;
     let config_address = config_pda().0;
     let mint_address = MINT_ADDRESS;
@@ -220,41 +271,54 @@ pub fn bury(signer: Pubkey, min_amount_out: u64) -> Instruction {
         ],
         data: Bury {
             min_amount_out: min_amount_out.to_le_bytes(),
+            wrap_amount: wrap_amount.to_le_bytes(),
         }
         .to_bytes(),
     }
 }
 
-pub fn wrap(signer: Pubkey) -> Instruction {
+pub fn wrap(signer: Pubkey, amount: u64) -> Instruction {
+    let board_address = board_pda().0;
     let config_address = config_pda().0;
     let treasury_address = TREASURY_ADDRESS;
     let treasury_sol_address = get_associated_token_address(&treasury_address, &TOKEN_B_MINT);
     Instruction {
         accounts: vec![
             AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(board_address, false),
             AccountMeta::new_readonly(config_address, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new(treasury_sol_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
         ],
         program_id: crate::ID,
-        data: Wrap {}.to_bytes(),
+        data: Wrap {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
     }
 }
 
-// let [signer_info, board_info, config_info, fee_collector_info, mint_info, round_info, round_next_info, top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, ore_program, slot_hashes_sysvar] =
+// let [signer_info, board_info, bonus_info, config_info, fee_collector_info, history_info, mint_info, round_info, round_next_info, season_info, sponsor_info, top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, ore_program, slot_hashes_sysvar] =
 
 pub fn reset(
     signer: Pubkey,
     fee_collector: Pubkey,
     round_id: u64,
+    season_id: u64,
+    sponsor: Pubkey,
     top_miner: Pubkey,
+    reveal: [u8; 32],
 ) -> Instruction {
     let board_address = board_pda().0;
+    let bonus_address = bonus_pda(round_id).0;
     let config_address = config_pda().0;
+    let history_address = history_pda().0;
     let mint_address = MINT_ADDRESS;
     let round_address = round_pda(round_id).0;
     let round_next_address = round_pda(round_id + 1).0;
+    let season_address = season_pda(season_id).0;
     let top_miner_address = miner_pda(top_miner).0;
     let treasury_address = TREASURY_ADDRESS;
     let treasury_tokens_address = treasury_tokens_address();
@@ -263,11 +327,15 @@ pub fn reset(
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(board_address, false),
+            AccountMeta::new(bonus_address, false),
             AccountMeta::new(config_address, false),
             AccountMeta::new(fee_collector, false),
+            AccountMeta::new(history_address, false),
             AccountMeta::new(mint_address, false),
             AccountMeta::new(round_address, false),
             AccountMeta::new(round_next_address, false),
+            AccountMeta::new(season_address, false),
+            AccountMeta::new(sponsor, false),
             AccountMeta::new(top_miner_address, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new(treasury_tokens_address, false),
@@ -276,14 +344,83 @@ pub fn reset(
             AccountMeta::new_readonly(crate::ID, false),
             AccountMeta::new_readonly(sysvar::slot_hashes::ID, false),
         ],
-        data: Reset {}.to_bytes(),
+        data: Reset { reveal }.to_bytes(),
+    }
+}
+
+// let [signer_info, board_info, bonus_info, mint_info, sender_info, treasury_info, treasury_tokens_info, system_program, token_program] =
+
+pub fn sponsor(signer: Pubkey, round_id: u64, amount_sol: u64, amount_ore: u64) -> Instruction {
+    let board_address = board_pda().0;
+    let bonus_address = bonus_pda(round_id).0;
+    let sender_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let treasury_address = TREASURY_ADDRESS;
+    let treasury_tokens_address = treasury_tokens_address();
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(board_address, false),
+            AccountMeta::new(bonus_address, false),
+            AccountMeta::new_readonly(MINT_ADDRESS, false),
+            AccountMeta::new(sender_address, false),
+            AccountMeta::new_readonly(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+        data: Sponsor {
+            round_id: round_id.to_le_bytes(),
+            amount_sol: amount_sol.to_le_bytes(),
+            amount_ore: amount_ore.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, fee_split_info, recipient_infos @ ..] =
+
+pub fn distribute_fees(signer: Pubkey, recipients: &[Pubkey]) -> Instruction {
+    let fee_split_address = fee_split_pda().0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(fee_split_address, false),
+    ];
+    accounts.extend(
+        recipients
+            .iter()
+            .map(|recipient| AccountMeta::new(*recipient, false)),
+    );
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: DistributeFees {}.to_bytes(),
+    }
+}
+
+// let [signer_info, board_info, config_info, round_info] =
+
+pub fn commit(signer: Pubkey, round_id: u64, commit: [u8; 32]) -> Instruction {
+    let board_address = board_pda().0;
+    let config_address = config_pda().0;
+    let round_address = round_pda(round_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(board_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(round_address, false),
+        ],
+        data: Commit { commit }.to_bytes(),
     }
 }
 
-// let [signer_info, board_info, rent_payer_info, round_info, treasury_info, system_program] =
+// let [signer_info, board_info, config_info, rent_payer_info, round_info, treasury_info, system_program, ore_program] =
 
 pub fn close(signer: Pubkey, round_id: u64, rent_payer: Pubkey) -> Instruction {
     let board_address = board_pda().0;
+    let config_address = config_pda().0;
     let treasury_address = TREASURY_ADDRESS;
     let round_address = round_pda(round_id).0;
     Instruction {
@@ -291,10 +428,12 @@ pub fn close(signer: Pubkey, round_id: u64, rent_payer: Pubkey) -> Instruction {
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(config_address, false),
             AccountMeta::new(rent_payer, false),
             AccountMeta::new(round_address, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
         ],
         data: Close {}.to_bytes(),
     }
@@ -302,20 +441,37 @@ pub fn close(signer: Pubkey, round_id: u64, rent_payer: Pubkey) -> Instruction {
 
 // let [signer_info, automation_info, board_info, miner_info, round_info, treasury_info, system_program] =
 
-pub fn checkpoint(signer: Pubkey, authority: Pubkey, round_id: u64) -> Instruction {
+// let [signer_info, board_info, miner_info, round_info, treasury_info, referrer_info, system_program, ore_program] =
+
+pub fn checkpoint(
+    signer: Pubkey,
+    authority: Pubkey,
+    round_id: u64,
+    season_id: u64,
+    referrer: Pubkey,
+) -> Instruction {
     let miner_address = miner_pda(authority).0;
     let board_address = board_pda().0;
+    let history_address = history_pda().0;
     let round_address = round_pda(round_id).0;
     let treasury_address = TREASURY_ADDRESS;
+    let referrer_address = referrer_pda(referrer).0;
+    let season_address = season_pda(season_id).0;
+    let season_miner_address = season_miner_pda(season_id, authority).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(board_address, false),
+            AccountMeta::new(history_address, false),
             AccountMeta::new(miner_address, false),
             AccountMeta::new(round_address, false),
             AccountMeta::new(treasury_address, false),
+            AccountMeta::new(referrer_address, false),
+            AccountMeta::new(season_address, false),
+            AccountMeta::new(season_miner_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
         ],
         data: Checkpoint {}.to_bytes(),
     }
@@ -353,107 +509,945 @@ pub fn set_fee_collector(signer: Pubkey, fee_collector: Pubkey) -> Instruction {
     }
 }
 
-// let [signer_info, mint_info, seeker_info, stake_info, token_account_info, system_program] =
+pub fn set_wrap_policy(signer: Pubkey, max_wrap: u64, treasury_reserve: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let treasury_address = TREASURY_ADDRESS;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetWrapPolicy {
+            max_wrap: max_wrap.to_le_bytes(),
+            treasury_reserve: treasury_reserve.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, fee_split_info, system_program] =
 
-pub fn claim_seeker(signer: Pubkey, mint: Pubkey) -> Instruction {
-    let seeker_address = seeker_pda(mint).0;
-    let stake_address = stake_pda(signer).0;
-    let token_account_address =
-        get_associated_token_address_with_program_id(&signer, &mint, &spl_token_2022::ID);
+pub fn set_fee_split(signer: Pubkey, recipients: &[(Pubkey, u64)]) -> Instruction {
+    let config_address = config_pda().0;
+    let fee_split_address = fee_split_pda().0;
+    let mut addresses = [[0; 32]; MAX_FEE_RECIPIENTS];
+    let mut bps = [[0; 8]; MAX_FEE_RECIPIENTS];
+    for (i, (address, weight)) in recipients.iter().take(MAX_FEE_RECIPIENTS).enumerate() {
+        addresses[i] = address.to_bytes();
+        bps[i] = weight.to_le_bytes();
+    }
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
-            AccountMeta::new_readonly(mint, false),
-            AccountMeta::new(seeker_address, false),
-            AccountMeta::new(stake_address, false),
-            AccountMeta::new(token_account_address, false),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(fee_split_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
-        data: ClaimSeeker {}.to_bytes(),
+        data: SetFeeSplit {
+            num_recipients: (recipients.len() as u64).to_le_bytes(),
+            addresses,
+            bps,
+        }
+        .to_bytes(),
+    }
+}
+
+pub fn set_entropy_source(
+    signer: Pubkey,
+    entropy_source: EntropySource,
+    randomness_authority: Pubkey,
+) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetEntropySource {
+            entropy_source: (entropy_source as u64).to_le_bytes(),
+            randomness_authority: randomness_authority.to_bytes(),
+        }
+        .to_bytes(),
     }
 }
 
-// let [signer_info, mint_info, sender_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
+pub fn set_crank_bounty(signer: Pubkey, reset_bounty: u64, close_bounty: u64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetCrankBounty {
+            reset_bounty: reset_bounty.to_le_bytes(),
+            close_bounty: close_bounty.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
 
-pub fn deposit(signer: Pubkey, amount: u64) -> Instruction {
-    let mint_address = MINT_ADDRESS;
-    let stake_address = stake_pda(signer).0;
-    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
-    let sender_address = get_associated_token_address(&signer, &MINT_ADDRESS);
-    let treasury_address = TREASURY_ADDRESS;
+pub fn set_close_destination(signer: Pubkey, close_destination: CloseDestination) -> Instruction {
+    let config_address = config_pda().0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
-            AccountMeta::new(mint_address, false),
-            AccountMeta::new(sender_address, false),
-            AccountMeta::new(stake_address, false),
-            AccountMeta::new(stake_tokens_address, false),
-            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(config_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
-            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         ],
-        data: Deposit {
-            amount: amount.to_le_bytes(),
+        data: SetCloseDestination {
+            close_destination: (close_destination as u64).to_le_bytes(),
         }
         .to_bytes(),
     }
 }
 
-// let [signer_info, mint_info, recipient_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
+pub fn set_stake_sol_yield(signer: Pubkey, admin_fee_bps: u64, vault_bps: u64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetStakeSolYield {
+            admin_fee_bps: admin_fee_bps.to_le_bytes(),
+            vault_bps: vault_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
 
-pub fn withdraw(signer: Pubkey, amount: u64) -> Instruction {
-    let stake_address = stake_pda(signer).0;
-    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
-    let mint_address = MINT_ADDRESS;
-    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
-    let treasury_address = TREASURY_ADDRESS;
+pub fn set_seeker_multiplier(signer: Pubkey, seeker_multiplier_bps: u64) -> Instruction {
+    let config_address = config_pda().0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
-            AccountMeta::new(mint_address, false),
-            AccountMeta::new(recipient_address, false),
-            AccountMeta::new(stake_address, false),
-            AccountMeta::new(stake_tokens_address, false),
-            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(config_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
-            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         ],
-        data: Withdraw {
-            amount: amount.to_le_bytes(),
+        data: SetSeekerMultiplier {
+            seeker_multiplier_bps: seeker_multiplier_bps.to_le_bytes(),
         }
         .to_bytes(),
     }
 }
 
-// let [signer_info, mint_info, recipient_info, stake_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+pub fn set_unstake_cooldown(signer: Pubkey, unstake_cooldown: i64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetUnstakeCooldown {
+            unstake_cooldown: unstake_cooldown.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
 
-pub fn claim_yield(signer: Pubkey, amount: u64) -> Instruction {
-    let stake_address = stake_pda(signer).0;
-    let mint_address = MINT_ADDRESS;
-    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
-    let treasury_address = TREASURY_ADDRESS;
-    let treasury_tokens_address = treasury_tokens_address();
+pub fn set_stake_claim_discount(signer: Pubkey, stake_claim_fee_discount_bps: u64) -> Instruction {
+    let config_address = config_pda().0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
-            AccountMeta::new(mint_address, false),
-            AccountMeta::new(recipient_address, false),
-            AccountMeta::new(stake_address, false),
-            AccountMeta::new(treasury_address, false),
-            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new(config_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
-            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         ],
-        data: ClaimYield {
-            amount: amount.to_le_bytes(),
+        data: SetStakeClaimDiscount {
+            stake_claim_fee_discount_bps: stake_claim_fee_discount_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub fn set_referral_fee(signer: Pubkey, referral_fee_bps: u64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetReferralFee {
+            referral_fee_bps: referral_fee_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub fn set_deploy_limits(
+    signer: Pubkey,
+    min_deploy_amount: u64,
+    max_deploy_per_round: u64,
+    max_deploy_per_square: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetDeployLimits {
+            min_deploy_amount: min_deploy_amount.to_le_bytes(),
+            max_deploy_per_round: max_deploy_per_round.to_le_bytes(),
+            max_deploy_per_square: max_deploy_per_square.to_le_bytes(),
         }
         .to_bytes(),
     }
 }
+
+pub fn add_to_allowlist(signer: Pubkey, authority: Pubkey) -> Instruction {
+    let allowlist_address = allowlist_pda(authority).0;
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(allowlist_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: AddToAllowlist {
+            authority: authority.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub fn remove_from_allowlist(signer: Pubkey, authority: Pubkey) -> Instruction {
+    let allowlist_address = allowlist_pda(authority).0;
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(allowlist_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: RemoveFromAllowlist {
+            authority: authority.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub fn set_allowlist_enabled(signer: Pubkey, enabled: bool) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetAllowlistEnabled {
+            enabled: (enabled as u64).to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub fn set_collection(
+    signer: Pubkey,
+    mint_authority: Pubkey,
+    metadata_address: Pubkey,
+    token_program: Pubkey,
+    perk: CollectionPerk,
+    perk_bps: u64,
+) -> Instruction {
+    let collection_address = collection_pda(mint_authority).0;
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(collection_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetCollection {
+            mint_authority: mint_authority.to_bytes(),
+            metadata_address: metadata_address.to_bytes(),
+            token_program: token_program.to_bytes(),
+            perk: (perk as u64).to_le_bytes(),
+            perk_bps: perk_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub fn remove_collection(signer: Pubkey, mint_authority: Pubkey) -> Instruction {
+    let collection_address = collection_pda(mint_authority).0;
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(collection_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: RemoveCollection {
+            mint_authority: mint_authority.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub fn set_seeker_activation(signer: Pubkey, enabled: bool) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetSeekerActivation {
+            enabled: (enabled as u64).to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, collection_info, config_info, mint_info, miner_info, seeker_info, stake_info, token_account_info, treasury_info, system_program] =
+
+pub fn set_winning_squares(signer: Pubkey, winning_squares: u64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetWinningSquares {
+            winning_squares: winning_squares.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub fn set_board_size(signer: Pubkey, squares: u64) -> Instruction {
+    let board_address = board_pda().0;
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetBoardSize {
+            squares: squares.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub fn claim_seeker(
+    signer: Pubkey,
+    mint: Pubkey,
+    mint_authority: Pubkey,
+    token_program: Pubkey,
+) -> Instruction {
+    let collection_address = collection_pda(mint_authority).0;
+    let config_address = config_pda().0;
+    let miner_address = miner_pda(signer).0;
+    let seeker_address = seeker_pda(mint).0;
+    let stake_address = stake_pda(signer).0;
+    let token_account_address =
+        get_associated_token_address_with_program_id(&signer, &mint, &token_program);
+    let treasury_address = TREASURY_ADDRESS;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(collection_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(seeker_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(token_account_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: ClaimSeeker {}.to_bytes(),
+    }
+}
+
+// let [signer_info, allowlist_info, config_info, mint_info, sender_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
+
+pub fn deposit(signer: Pubkey, amount: u64, lock_tier: u64) -> Instruction {
+    let allowlist_address = allowlist_pda(signer).0;
+    let config_address = config_pda().0;
+    let mint_address = MINT_ADDRESS;
+    let stake_address = stake_pda(signer).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
+    let sender_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let treasury_address = TREASURY_ADDRESS;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(allowlist_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(mint_address, false),
+            AccountMeta::new(sender_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: Deposit {
+            amount: amount.to_le_bytes(),
+            lock_tier: lock_tier.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, stake_info, treasury_info] =
+
+pub fn unstake(signer: Pubkey, amount: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let stake_address = stake_pda(signer).0;
+    let treasury_address = TREASURY_ADDRESS;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(treasury_address, false),
+        ],
+        data: Unstake {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, mint_info, recipient_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
+
+pub fn withdraw(signer: Pubkey, amount: u64) -> Instruction {
+    let stake_address = stake_pda(signer).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
+    let mint_address = MINT_ADDRESS;
+    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let treasury_address = TREASURY_ADDRESS;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(mint_address, false),
+            AccountMeta::new(recipient_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: Withdraw {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, mint_info, recipient_info, stake_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+
+pub fn claim_yield(signer: Pubkey, amount: u64) -> Instruction {
+    let stake_address = stake_pda(signer).0;
+    let mint_address = MINT_ADDRESS;
+    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let treasury_address = TREASURY_ADDRESS;
+    let treasury_tokens_address = treasury_tokens_address();
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(mint_address, false),
+            AccountMeta::new(recipient_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: ClaimYield {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, stake_info, treasury_info, system_program] =
+
+pub fn claim_yield_sol(signer: Pubkey, amount: u64) -> Instruction {
+    let stake_address = stake_pda(signer).0;
+    let treasury_address = TREASURY_ADDRESS;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: ClaimYieldSol {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, mint_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, token_program] =
+
+pub fn compound_yield(signer: Pubkey, authority: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let mint_address = MINT_ADDRESS;
+    let stake_address = stake_pda(authority).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
+    let treasury_address = TREASURY_ADDRESS;
+    let treasury_tokens_address = treasury_tokens_address();
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(mint_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+        data: CompoundYield {}.to_bytes(),
+    }
+}
+
+// let [signer_info, stake_info] =
+
+pub fn set_auto_compound(signer: Pubkey, auto_compound: bool) -> Instruction {
+    let stake_address = stake_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(stake_address, false),
+        ],
+        data: SetAutoCompound {
+            auto_compound: (auto_compound as u64).to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, miner_info] =
+
+pub fn set_claim_delegate(
+    signer: Pubkey,
+    claim_delegate: Pubkey,
+    payout_recipient: Pubkey,
+) -> Instruction {
+    let miner_address = miner_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(miner_address, false),
+        ],
+        data: SetClaimDelegate {
+            claim_delegate: claim_delegate.to_bytes(),
+            payout_recipient: payout_recipient.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, referrer_info, system_program] =
+
+pub fn claim_referral(signer: Pubkey) -> Instruction {
+    let referrer_address = referrer_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(referrer_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: ClaimReferral {}.to_bytes(),
+    }
+}
+
+// let [signer_info, new_authority_info, automation_info, config_info, miner_info, new_miner_info, season_info, season_miner_info, treasury_info, system_program] =
+
+pub fn transfer_miner(signer: Pubkey, new_authority: Pubkey, season_id: u64) -> Instruction {
+    let automation_address = automation_pda(signer).0;
+    let config_address = config_pda().0;
+    let miner_address = miner_pda(signer).0;
+    let new_miner_address = miner_pda(new_authority).0;
+    let season_address = season_pda(season_id).0;
+    let season_miner_address = season_miner_pda(season_id, signer).0;
+    let treasury_address = TREASURY_ADDRESS;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(new_authority, true),
+            AccountMeta::new_readonly(automation_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(new_miner_address, false),
+            AccountMeta::new_readonly(season_address, false),
+            AccountMeta::new_readonly(season_miner_address, false),
+            AccountMeta::new_readonly(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: TransferMiner {}.to_bytes(),
+    }
+}
+
+// let [signer_info, new_authority_info, mint_info, stake_info, stake_tokens_info, new_stake_info, new_stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
+
+pub fn transfer_stake(signer: Pubkey, new_authority: Pubkey) -> Instruction {
+    let mint_address = MINT_ADDRESS;
+    let stake_address = stake_pda(signer).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
+    let new_stake_address = stake_pda(new_authority).0;
+    let new_stake_tokens_address = get_associated_token_address(&new_stake_address, &MINT_ADDRESS);
+    let treasury_address = TREASURY_ADDRESS;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(new_authority, true),
+            AccountMeta::new_readonly(mint_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new(new_stake_address, false),
+            AccountMeta::new(new_stake_tokens_address, false),
+            AccountMeta::new_readonly(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: TransferStake {}.to_bytes(),
+    }
+}
+
+// let [signer_info, automation_info, miner_info, system_program] =
+
+pub fn close_miner(signer: Pubkey) -> Instruction {
+    let automation_address = automation_pda(signer).0;
+    let miner_address = miner_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(automation_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: CloseMiner {}.to_bytes(),
+    }
+}
+
+// let [signer_info, mint_info, stake_info, stake_tokens_info, treasury_info, token_program] =
+
+pub fn close_stake(signer: Pubkey) -> Instruction {
+    let mint_address = MINT_ADDRESS;
+    let stake_address = stake_pda(signer).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
+    let treasury_address = TREASURY_ADDRESS;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(mint_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new_readonly(treasury_address, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+        data: CloseStake {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, stake_info, treasury_info] =
+
+pub fn refresh_stake(signer: Pubkey, authority: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let stake_address = stake_pda(authority).0;
+    let treasury_address = TREASURY_ADDRESS;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(treasury_address, false),
+        ],
+        data: RefreshStake {}.to_bytes(),
+    }
+}
+
+// let [signer_info, pool_info, system_program] =
+
+pub fn create_pool(signer: Pubkey, fee_bps: u64) -> Instruction {
+    let pool_address = pool_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(pool_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: CreatePool {
+            fee_bps: fee_bps.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, allowlist_info, config_info, member_info, pool_info, system_program] =
+
+pub fn join_pool(signer: Pubkey, operator: Pubkey, amount: u64) -> Instruction {
+    let allowlist_address = allowlist_pda(signer).0;
+    let config_address = config_pda().0;
+    let pool_address = pool_pda(operator).0;
+    let member_address = pool_member_pda(pool_address, signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(allowlist_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(member_address, false),
+            AccountMeta::new(pool_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: JoinPool {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, member_info, pool_info] =
+
+pub fn leave_pool(signer: Pubkey, operator: Pubkey) -> Instruction {
+    let pool_address = pool_pda(operator).0;
+    let member_address = pool_member_pda(pool_address, signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(member_address, false),
+            AccountMeta::new(pool_address, false),
+        ],
+        data: LeavePool {}.to_bytes(),
+    }
+}
+
+// let [signer_info, member_info, mint_info, pool_info, pool_tokens_info, recipient_info, system_program, token_program, associated_token_program] =
+
+pub fn claim_pool(signer: Pubkey, operator: Pubkey) -> Instruction {
+    let pool_address = pool_pda(operator).0;
+    let member_address = pool_member_pda(pool_address, signer).0;
+    let pool_tokens_address = get_associated_token_address(&pool_address, &MINT_ADDRESS);
+    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(member_address, false),
+            AccountMeta::new_readonly(MINT_ADDRESS, false),
+            AccountMeta::new(pool_address, false),
+            AccountMeta::new(pool_tokens_address, false),
+            AccountMeta::new(recipient_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: ClaimPool {}.to_bytes(),
+    }
+}
+
+// let [signer_info, miner_info, mint_info, pool_info, pool_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+
+pub fn harvest_pool(signer: Pubkey, operator: Pubkey) -> Instruction {
+    let pool_address = pool_pda(operator).0;
+    let miner_address = miner_pda(pool_address).0;
+    let pool_tokens_address = get_associated_token_address(&pool_address, &MINT_ADDRESS);
+    let treasury_address = TREASURY_ADDRESS;
+    let treasury_tokens_address = treasury_tokens_address();
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new_readonly(MINT_ADDRESS, false),
+            AccountMeta::new(pool_address, false),
+            AccountMeta::new(pool_tokens_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: HarvestPool {}.to_bytes(),
+    }
+}
+
+pub fn create_season(
+    signer: Pubkey,
+    prev_season_id: u64,
+    start_round: u64,
+    end_round: u64,
+) -> Instruction {
+    let board_address = board_pda().0;
+    let config_address = config_pda().0;
+    let season_address = season_pda(prev_season_id + 1).0;
+    let prev_season_address = season_pda(prev_season_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(board_address, false),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(season_address, false),
+            AccountMeta::new_readonly(prev_season_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: CreateSeason {
+            start_round: start_round.to_le_bytes(),
+            end_round: end_round.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub fn fund_season(
+    signer: Pubkey,
+    season_id: u64,
+    amount_sol: u64,
+    amount_ore: u64,
+) -> Instruction {
+    let season_address = season_pda(season_id).0;
+    let sender_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let treasury_address = TREASURY_ADDRESS;
+    let treasury_tokens_address = treasury_tokens_address();
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(MINT_ADDRESS, false),
+            AccountMeta::new(season_address, false),
+            AccountMeta::new(sender_address, false),
+            AccountMeta::new_readonly(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+        data: FundSeason {
+            season_id: season_id.to_le_bytes(),
+            amount_sol: amount_sol.to_le_bytes(),
+            amount_ore: amount_ore.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub fn claim_season(signer: Pubkey, season_id: u64) -> Instruction {
+    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let season_address = season_pda(season_id).0;
+    let season_miner_address = season_miner_pda(season_id, signer).0;
+    let treasury_address = TREASURY_ADDRESS;
+    let treasury_tokens_address = treasury_tokens_address();
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(MINT_ADDRESS, false),
+            AccountMeta::new(recipient_address, false),
+            AccountMeta::new(season_address, false),
+            AccountMeta::new(season_miner_address, false),
+            AccountMeta::new_readonly(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: ClaimSeason {
+            season_id: season_id.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, account_info, system_program] =
+
+pub fn migrate(signer: Pubkey, address: Pubkey) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: Migrate {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, mint_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+
+pub fn migrate_staker(signer: Pubkey, authority: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let stake_address = stake_pda(authority).0;
+    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
+    let treasury_address = TREASURY_ADDRESS;
+    let treasury_tokens_address = treasury_tokens_address();
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new_readonly(MINT_ADDRESS, false),
+            AccountMeta::new(stake_address, false),
+            AccountMeta::new(stake_tokens_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+        data: MigrateStaker {}.to_bytes(),
+    }
+}
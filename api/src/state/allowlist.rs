@@ -0,0 +1,26 @@
+use steel::*;
+
+use crate::state::allowlist_pda;
+
+use super::{Migratable, OreAccount};
+
+/// Allowlist marks an authority as permitted to mine and stake while the allowlist is enabled.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Allowlist {
+    /// The authority permitted by this allowlist entry.
+    pub authority: Pubkey,
+
+    /// The time this entry was added.
+    pub created_at: i64,
+}
+
+impl Allowlist {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        allowlist_pda(self.authority)
+    }
+}
+
+account!(OreAccount, Allowlist);
+
+impl Migratable for Allowlist {}
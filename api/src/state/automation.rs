@@ -2,7 +2,7 @@ use steel::*;
 
 use crate::state::miner_pda;
 
-use super::OreAccount;
+use super::{Migratable, OreAccount};
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
@@ -50,3 +50,5 @@ impl Automation {
 }
 
 account!(OreAccount, Automation);
+
+impl Migratable for Automation {}
@@ -1,8 +1,11 @@
 use steel::*;
 
-use crate::state::board_pda;
+use crate::{
+    consts::{DEFAULT_SQUARES, MAX_SQUARES},
+    state::board_pda,
+};
 
-use super::OreAccount;
+use super::{Migratable, OreAccount};
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
@@ -15,12 +18,25 @@ pub struct Board {
 
     /// The slot at which the current round ends mining.
     pub end_slot: u64,
+
+    /// The number of squares on the board for new rounds (0 for the default).
+    pub squares: u64,
 }
 
 impl Board {
     pub fn pda(&self) -> (Pubkey, u8) {
         board_pda()
     }
+
+    /// Returns the number of squares on the board for new rounds.
+    pub fn num_squares(&self) -> usize {
+        match self.squares as usize {
+            0 => DEFAULT_SQUARES,
+            squares => squares.min(MAX_SQUARES),
+        }
+    }
 }
 
 account!(OreAccount, Board);
+
+impl Migratable for Board {}
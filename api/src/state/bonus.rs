@@ -0,0 +1,33 @@
+use steel::*;
+
+use crate::state::bonus_pda;
+
+use super::{Migratable, OreAccount};
+
+/// Bonus escrows sponsored SOL and ORE for a round, until the round is reset.
+/// The ORE is held in the treasury token account.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Bonus {
+    /// The round this bonus is paid out in.
+    pub round_id: u64,
+
+    /// The amount of SOL sponsored for the round.
+    pub amount_sol: u64,
+
+    /// The amount of ORE sponsored for the round.
+    pub amount_ore: u64,
+
+    /// The sponsor who opened this account, and to whom its rent is returned at reset.
+    pub sponsor: Pubkey,
+}
+
+impl Bonus {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        bonus_pda(self.round_id)
+    }
+}
+
+account!(OreAccount, Bonus);
+
+impl Migratable for Bonus {}
@@ -0,0 +1,58 @@
+use steel::*;
+
+use crate::state::collection_pda;
+
+use super::{Migratable, OreAccount};
+
+/// Collection registers an NFT collection whose tokens can be claimed for a perk.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Collection {
+    /// The mint authority shared by all tokens in this collection.
+    pub mint_authority: Pubkey,
+
+    /// The metadata address tokens must point to, or the default pubkey to skip the check.
+    pub metadata_address: Pubkey,
+
+    /// The token program that owns the collection's mints (SPL Token or Token-2022).
+    pub token_program: Pubkey,
+
+    /// The perk granted to the claimer of a token.
+    pub perk: u64,
+
+    /// The size of the perk, in basis points.
+    pub perk_bps: u64,
+
+    /// The number of tokens claimed from this collection.
+    pub total_claimed: u64,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum CollectionPerk {
+    /// Multiplies the claimer's stake weight.
+    StakeMultiplier = 0,
+
+    /// Discounts the claimer's ORE claim fee.
+    ClaimFeeDiscount = 1,
+}
+
+impl CollectionPerk {
+    pub fn from_u64(value: u64) -> Self {
+        Self::try_from(value as u8).unwrap()
+    }
+}
+
+impl Collection {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        collection_pda(self.mint_authority)
+    }
+
+    pub fn perk(&self) -> CollectionPerk {
+        CollectionPerk::from_u64(self.perk)
+    }
+}
+
+account!(OreAccount, Collection);
+
+impl Migratable for Collection {}
@@ -1,8 +1,11 @@
 use steel::*;
 
-use crate::state::config_pda;
+use crate::{
+    consts::{DEFAULT_MAX_WRAP, DENOMINATOR_BPS},
+    state::{config_pda, EntropySource},
+};
 
-use super::OreAccount;
+use super::{is_appended, Migratable, OreAccount};
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
@@ -21,12 +24,169 @@ pub struct Config {
 
     /// Whether seeker activation is enabled.
     pub is_seeker_activation_enabled: u64,
+
+    /// The address with authority to commit and reveal entropy for commit-reveal rounds.
+    pub randomness_authority: Pubkey,
+
+    /// The entropy source to use for new rounds.
+    pub entropy_source: u64,
+
+    /// The SOL bounty paid from the treasury to the caller of reset.
+    pub reset_bounty: u64,
+
+    /// The SOL bounty paid from the treasury to the caller of close.
+    pub close_bounty: u64,
+
+    /// The destination of unclaimed SOL from expired rounds.
+    pub close_destination: u64,
+
+    /// The share of admin fees paid to stakers as SOL yield, in basis points.
+    pub stake_admin_fee_bps: u64,
+
+    /// The share of the vault cut paid to stakers as SOL yield, in basis points.
+    pub stake_vault_bps: u64,
+
+    /// The extra reward weight multiplier for Seeker stakers, in basis points (0 to disable).
+    pub seeker_multiplier_bps: u64,
+
+    /// The number of seconds unstaked ORE must wait before it can be withdrawn.
+    pub unstake_cooldown: i64,
+
+    /// The discount on the ORE claim fee for miners who claim straight into a stake account, in basis points.
+    pub stake_claim_fee_discount_bps: u64,
+
+    /// The share of admin fees on referred deployments paid to referrers, in basis points.
+    pub referral_fee_bps: u64,
+
+    /// The minimum amount of SOL a miner can deploy to a square.
+    pub min_deploy_amount: u64,
+
+    /// The maximum amount of SOL a miner can deploy in a single round (0 for no limit).
+    pub max_deploy_per_round: u64,
+
+    /// The maximum amount of SOL a miner can deploy to a single square (0 for no limit).
+    pub max_deploy_per_square: u64,
+
+    /// Whether only allowlisted authorities can deploy, automate, and stake.
+    pub allowlist_enabled: u64,
+
+    /// The id of the latest season (0 if none).
+    pub season_id: u64,
+
+    /// The number of squares that win each new round (0 or 1 for a single winning square).
+    pub winning_squares: u64,
+
+    /// The maximum amount of SOL that can be wrapped from the treasury in a single call.
+    pub max_wrap: u64,
+
+    /// The amount of SOL kept in the treasury balance as an operating reserve, which is never wrapped.
+    pub treasury_reserve: u64,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum CloseDestination {
+    /// Added to the treasury balance for buy-and-bury.
+    Treasury = 0,
+
+    /// Added to the SOL motherlode, paid out alongside the ORE motherlode.
+    Motherlode = 1,
+
+    /// Distributed directly to stakers.
+    Stakers = 2,
+}
+
+impl CloseDestination {
+    pub fn from_u64(value: u64) -> Result<Self, ProgramError> {
+        u8::try_from(value)
+            .ok()
+            .and_then(|v| Self::try_from(v).ok())
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
 }
 
 impl Config {
     pub fn pda() -> (Pubkey, u8) {
         config_pda()
     }
+
+    pub fn entropy_source(&self) -> EntropySource {
+        EntropySource::from_u64(self.entropy_source).unwrap_or(EntropySource::SlotHash)
+    }
+
+    pub fn close_destination(&self) -> CloseDestination {
+        CloseDestination::from_u64(self.close_destination).unwrap_or(CloseDestination::Treasury)
+    }
+
+    /// Returns the staker share of the given admin fee.
+    pub fn stake_admin_fee_share(&self, admin_fee: u64) -> u64 {
+        ((admin_fee as u128 * self.stake_admin_fee_bps as u128) / DENOMINATOR_BPS as u128) as u64
+    }
+
+    /// Returns the referrer share of the given admin fee on referred deployments.
+    pub fn referral_fee_share(&self, admin_fee: u64) -> u64 {
+        ((admin_fee as u128 * self.referral_fee_bps as u128) / DENOMINATOR_BPS as u128) as u64
+    }
+
+    /// Returns the staker share of the given vault amount.
+    pub fn stake_vault_share(&self, vault_amount: u64) -> u64 {
+        ((vault_amount as u128 * self.stake_vault_bps as u128) / DENOMINATOR_BPS as u128) as u64
+    }
+
+    /// Returns the amount of SOL that can be wrapped from the treasury for the requested amount,
+    /// bounded by the per-call cap and the reserve kept in the treasury balance.
+    pub fn wrap_amount(&self, treasury_balance: u64, requested: u64) -> u64 {
+        requested
+            .min(self.max_wrap)
+            .min(treasury_balance.saturating_sub(self.treasury_reserve))
+    }
 }
 
 account!(OreAccount, Config);
+
+impl Migratable for Config {
+    fn migrate(&mut self, prior_len: usize) {
+        // Configs created before the wrap cap was configurable used the default cap.
+        if is_appended(prior_len, std::mem::offset_of!(Config, max_wrap)) {
+            self.max_wrap = DEFAULT_MAX_WRAP;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_close_destination_from_u64() {
+        assert_eq!(
+            CloseDestination::from_u64(0),
+            Ok(CloseDestination::Treasury)
+        );
+        assert_eq!(CloseDestination::from_u64(2), Ok(CloseDestination::Stakers));
+        assert!(CloseDestination::from_u64(3).is_err());
+        assert!(CloseDestination::from_u64(256).is_err());
+    }
+
+    #[test]
+    fn test_wrap_amount() {
+        let mut config = Config::zeroed();
+        config.max_wrap = 1_000;
+
+        // The requested amount is capped per call.
+        assert_eq!(config.wrap_amount(5_000, 500), 500);
+        assert_eq!(config.wrap_amount(5_000, 2_000), 1_000);
+        assert_eq!(config.wrap_amount(600, 2_000), 600);
+
+        // The reserve is never wrapped.
+        config.treasury_reserve = 4_500;
+        assert_eq!(config.wrap_amount(5_000, 2_000), 500);
+        assert_eq!(config.wrap_amount(4_000, 2_000), 0);
+
+        // Configs created before the cap was configurable use the default cap.
+        let mut config = Config::zeroed();
+        config.migrate(8 + std::mem::offset_of!(Config, max_wrap));
+        assert_eq!(config.max_wrap, DEFAULT_MAX_WRAP);
+        assert_eq!(config.treasury_reserve, 0);
+    }
+}
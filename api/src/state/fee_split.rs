@@ -0,0 +1,140 @@
+use steel::*;
+
+use crate::{
+    consts::{DENOMINATOR_BPS, MAX_FEE_RECIPIENTS},
+    state::fee_split_pda,
+};
+
+use super::{Migratable, OreAccount};
+
+/// FeeSplit splits admin fees between several recipients. When it is the fee collector, admin
+/// fees accrue to this account at reset, and are paid out to recipients by weight on distribution.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct FeeSplit {
+    /// The number of recipients in the split.
+    pub num_recipients: u64,
+
+    /// The recipients of the split, and their weights.
+    pub recipients: [FeeRecipient; MAX_FEE_RECIPIENTS],
+
+    /// The amount of SOL accrued and awaiting distribution.
+    pub balance: u64,
+
+    /// The total amount of SOL distributed to recipients.
+    pub total_distributed: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct FeeRecipient {
+    /// The address receiving fees.
+    pub address: Pubkey,
+
+    /// The share of fees paid to the address, in basis points.
+    pub bps: u64,
+}
+
+impl FeeSplit {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        fee_split_pda()
+    }
+
+    /// Returns the recipients of the split.
+    pub fn recipients(&self) -> &[FeeRecipient] {
+        let len = (self.num_recipients as usize).min(MAX_FEE_RECIPIENTS);
+        &self.recipients[..len]
+    }
+
+    /// Returns true if the given recipients form a valid split: between one and
+    /// `MAX_FEE_RECIPIENTS` distinct addresses, whose weights sum to `DENOMINATOR_BPS`.
+    pub fn is_valid(recipients: &[FeeRecipient]) -> bool {
+        if recipients.is_empty() || recipients.len() > MAX_FEE_RECIPIENTS {
+            return false;
+        }
+        for (i, r) in recipients.iter().enumerate() {
+            if r.address == Pubkey::default()
+                || r.bps == 0
+                || recipients[..i].iter().any(|p| p.address == r.address)
+            {
+                return false;
+            }
+        }
+        recipients.iter().map(|r| r.bps).sum::<u64>() == DENOMINATOR_BPS
+    }
+
+    /// Returns the amount of the given fees owed to a recipient, by its weight.
+    pub fn share(&self, recipient: &FeeRecipient, amount: u64) -> u64 {
+        (amount as u128 * recipient.bps as u128 / DENOMINATOR_BPS as u128) as u64
+    }
+}
+
+account!(OreAccount, FeeSplit);
+
+impl Migratable for FeeSplit {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recipient(bps: u64) -> FeeRecipient {
+        FeeRecipient {
+            address: Pubkey::new_unique(),
+            bps,
+        }
+    }
+
+    #[test]
+    fn test_is_valid() {
+        // Weights must sum to the denominator.
+        assert!(FeeSplit::is_valid(&[recipient(DENOMINATOR_BPS)]));
+        assert!(FeeSplit::is_valid(&[
+            recipient(5_000),
+            recipient(3_000),
+            recipient(2_000)
+        ]));
+        assert!(!FeeSplit::is_valid(&[recipient(5_000), recipient(4_000)]));
+        assert!(!FeeSplit::is_valid(&[recipient(6_000), recipient(5_000)]));
+
+        // Splits must have between one and the maximum number of recipients.
+        assert!(!FeeSplit::is_valid(&[]));
+        let too_many: Vec<_> = (0..=MAX_FEE_RECIPIENTS)
+            .map(|_| recipient(DENOMINATOR_BPS / (MAX_FEE_RECIPIENTS as u64 + 1)))
+            .collect();
+        assert!(!FeeSplit::is_valid(&too_many));
+
+        // Recipients must be distinct, non-default, and weighted.
+        let r = recipient(5_000);
+        assert!(!FeeSplit::is_valid(&[r, r]));
+        assert!(!FeeSplit::is_valid(&[
+            FeeRecipient {
+                address: Pubkey::default(),
+                bps: 5_000,
+            },
+            recipient(5_000)
+        ]));
+        assert!(!FeeSplit::is_valid(&[
+            recipient(0),
+            recipient(DENOMINATOR_BPS)
+        ]));
+    }
+
+    #[test]
+    fn test_share() {
+        let mut fee_split = FeeSplit::zeroed();
+        let recipients = [recipient(5_000), recipient(3_333), recipient(1_667)];
+        fee_split.num_recipients = recipients.len() as u64;
+        fee_split.recipients[..recipients.len()].copy_from_slice(&recipients);
+        assert_eq!(fee_split.recipients(), &recipients);
+
+        // Shares are paid by weight, and never exceed the fees.
+        let amount = 1_000_001;
+        let shares: Vec<u64> = fee_split
+            .recipients()
+            .iter()
+            .map(|r| fee_split.share(r, amount))
+            .collect();
+        assert_eq!(shares, vec![500_000, 333_300, 166_700]);
+        assert!(shares.iter().sum::<u64>() <= amount);
+    }
+}
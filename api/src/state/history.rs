@@ -0,0 +1,112 @@
+use steel::*;
+
+use crate::{consts::HISTORY_SIZE, state::history_pda};
+
+use super::{Migratable, OreAccount};
+
+/// History keeps a compact summary of recent rounds, so results remain on chain after round
+/// accounts are closed. Summaries are written to a ring buffer at reset.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct History {
+    /// The total number of rounds recorded.
+    pub total: u64,
+
+    /// The summaries of recent rounds, indexed by the number of rounds recorded before them.
+    pub rounds: [RoundSummary; HISTORY_SIZE],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct RoundSummary {
+    /// The round number.
+    pub round_id: u64,
+
+    /// A bitmask of the winning squares (0 if the round was refunded).
+    pub winning_mask: u64,
+
+    /// The total amount of SOL deployed in the round.
+    pub total_deployed: u64,
+
+    /// The total amount of SOL won by miners for the round.
+    pub total_winnings: u64,
+
+    /// The top miner of the round, once they have checkpointed.
+    pub top_miner: Pubkey,
+
+    /// The amount of ORE paid out from the motherlode.
+    pub motherlode: u64,
+
+    /// Whether the top miner reward was split among all winning miners.
+    pub is_split: u64,
+}
+
+impl History {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        history_pda()
+    }
+
+    /// Records the summary of a round, overwriting the oldest summary once the buffer is full.
+    pub fn push(&mut self, summary: RoundSummary) {
+        self.rounds[(self.total % HISTORY_SIZE as u64) as usize] = summary;
+        self.total += 1;
+    }
+
+    /// Returns the summary of the given round, if it is still recorded.
+    pub fn get_mut(&mut self, round_id: u64) -> Option<&mut RoundSummary> {
+        let len = self.total.min(HISTORY_SIZE as u64) as usize;
+        self.rounds[..len]
+            .iter_mut()
+            .find(|r| r.round_id == round_id)
+    }
+
+    /// Returns the recorded summaries, newest first.
+    pub fn latest(&self) -> impl Iterator<Item = &RoundSummary> {
+        let len = self.total.min(HISTORY_SIZE as u64);
+        (1..=len).map(move |i| &self.rounds[((self.total - i) % HISTORY_SIZE as u64) as usize])
+    }
+}
+
+account!(OreAccount, History);
+
+impl Migratable for History {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(round_id: u64) -> RoundSummary {
+        RoundSummary {
+            round_id,
+            ..RoundSummary::zeroed()
+        }
+    }
+
+    #[test]
+    fn test_history_ring_buffer() {
+        let mut history = History::zeroed();
+        assert_eq!(history.latest().count(), 0);
+
+        // Summaries are returned newest first.
+        for id in 1..=3 {
+            history.push(summary(id));
+        }
+        let ids: Vec<u64> = history.latest().map(|r| r.round_id).collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+
+        // Once full, the oldest summaries are overwritten.
+        for id in 4..=HISTORY_SIZE as u64 + 5 {
+            history.push(summary(id));
+        }
+        let ids: Vec<u64> = history.latest().map(|r| r.round_id).collect();
+        assert_eq!(ids.len(), HISTORY_SIZE);
+        assert_eq!(ids[0], HISTORY_SIZE as u64 + 5);
+        assert_eq!(ids[HISTORY_SIZE - 1], 6);
+        assert!(history.get_mut(5).is_none());
+
+        // Recorded summaries can be updated.
+        let top_miner = Pubkey::new_unique();
+        history.get_mut(6).unwrap().top_miner = top_miner;
+        assert_eq!(history.latest().last().unwrap().top_miner, top_miner);
+    }
+}
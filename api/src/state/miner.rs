@@ -1,8 +1,11 @@
 use steel::*;
 
-use crate::state::{miner_pda, Treasury};
+use crate::{
+    consts::{DENOMINATOR_BPS, MAX_SQUARES},
+    state::{is_winning_square, miner_pda, Treasury},
+};
 
-use super::OreAccount;
+use super::{is_legacy_squares, widen_squares, Migratable, OreAccount};
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
@@ -11,10 +14,10 @@ pub struct Miner {
     pub authority: Pubkey,
 
     /// The miner's prospects in the current round.
-    pub deployed: [u64; 25],
+    pub deployed: [u64; MAX_SQUARES],
 
     /// The cumulative amount of SOL deployed on each square prior to this miner's move.
-    pub cumulative: [u64; 25],
+    pub cumulative: [u64; MAX_SQUARES],
 
     /// SOL witheld in reserve to pay for checkpointing.
     pub checkpoint_fee: u64,
@@ -48,6 +51,18 @@ pub struct Miner {
 
     /// The total amount of ORE this miner has mined across all blocks.
     pub lifetime_rewards_ore: u64,
+
+    /// An address allowed to trigger claims on behalf of the authority, or the default pubkey if none.
+    pub claim_delegate: Pubkey,
+
+    /// The address claimed rewards are paid to, or the default pubkey to pay the authority.
+    pub payout_recipient: Pubkey,
+
+    /// The address that referred this miner, or the default pubkey if none. Set once.
+    pub referrer: Pubkey,
+
+    /// The discount on the ORE claim fee granted by a collection perk, in basis points.
+    pub claim_fee_discount_bps: u64,
 }
 
 impl Miner {
@@ -55,7 +70,28 @@ impl Miner {
         miner_pda(self.authority)
     }
 
-    pub fn claim_ore(&mut self, clock: &Clock, treasury: &mut Treasury) -> u64 {
+    /// Returns true if the given address may trigger claims on this miner account.
+    pub fn can_claim(&self, signer: &Pubkey) -> bool {
+        self.authority == *signer
+            || (self.claim_delegate != Pubkey::default() && self.claim_delegate == *signer)
+    }
+
+    /// Returns the address claimed rewards are paid to.
+    pub fn payout_recipient(&self) -> Pubkey {
+        if self.payout_recipient == Pubkey::default() {
+            self.authority
+        } else {
+            self.payout_recipient
+        }
+    }
+
+    /// Claims all ORE rewards. The claim fee is reduced by the given discount, in basis points.
+    pub fn claim_ore(
+        &mut self,
+        clock: &Clock,
+        treasury: &mut Treasury,
+        fee_discount_bps: u64,
+    ) -> u64 {
         self.update_rewards(treasury);
         let refined_ore = self.refined_ore;
         let rewards_ore = self.rewards_ore;
@@ -69,6 +105,8 @@ impl Miner {
         // Charge a 10% fee and share with miners who haven't claimed yet.
         if treasury.total_unclaimed > 0 {
             let fee = rewards_ore / 10;
+            let fee = ((fee as u128 * DENOMINATOR_BPS.saturating_sub(fee_discount_bps) as u128)
+                / DENOMINATOR_BPS as u128) as u64;
             amount -= fee;
             treasury.miner_rewards_factor += Numeric::from_fraction(fee, treasury.total_unclaimed);
             treasury.total_refined += fee;
@@ -85,6 +123,16 @@ impl Miner {
         amount
     }
 
+    /// Returns the amount of SOL the miner deployed on the winning squares.
+    pub fn winning_deployed(&self, mask: u64) -> u64 {
+        self.deployed
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| is_winning_square(mask, *i))
+            .map(|(_, d)| d)
+            .sum()
+    }
+
     pub fn update_rewards(&mut self, treasury: &Treasury) {
         // Accumulate rewards, weighted by stake balance.
         if treasury.miner_rewards_factor > self.rewards_factor {
@@ -103,3 +151,63 @@ impl Miner {
 }
 
 account!(OreAccount, Miner);
+
+impl Migratable for Miner {
+    fn migrate(&mut self, prior_len: usize) {
+        // Miners created before the board size was configurable hold the default squares.
+        if is_legacy_squares(prior_len, std::mem::offset_of!(Miner, checkpoint_fee)) {
+            widen_squares(
+                bytemuck::bytes_of_mut(self),
+                prior_len,
+                &[
+                    std::mem::offset_of!(Miner, deployed),
+                    std::mem::offset_of!(Miner, cumulative),
+                ],
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_ore_fee_discount() {
+        let clock = Clock::default();
+        for (fee_discount_bps, expected) in [(0, 900), (5_000, 950), (DENOMINATOR_BPS, 1_000)] {
+            let mut treasury = Treasury::zeroed();
+            treasury.total_unclaimed = 2_000;
+            let mut miner = Miner::zeroed();
+            miner.rewards_ore = 1_000;
+            miner.lifetime_rewards_ore = 1_000;
+            assert_eq!(
+                miner.claim_ore(&clock, &mut treasury, fee_discount_bps),
+                expected
+            );
+            assert_eq!(treasury.total_refined, 1_000 - expected);
+        }
+    }
+
+    #[test]
+    fn test_claim_delegate() {
+        let authority = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let mut miner = Miner::zeroed();
+        miner.authority = authority;
+
+        // Without a delegate, only the authority can claim, and rewards are paid to the authority.
+        assert!(miner.can_claim(&authority));
+        assert!(!miner.can_claim(&delegate));
+        assert!(!miner.can_claim(&Pubkey::default()));
+        assert_eq!(miner.payout_recipient(), authority);
+
+        // With a delegate, both can claim, and rewards are paid to the designated recipient.
+        miner.claim_delegate = delegate;
+        miner.payout_recipient = recipient;
+        assert!(miner.can_claim(&authority));
+        assert!(miner.can_claim(&delegate));
+        assert_eq!(miner.payout_recipient(), recipient);
+    }
+}
@@ -1,17 +1,37 @@
+mod allowlist;
 mod automation;
 mod board;
+mod bonus;
+mod collection;
 mod config;
+mod fee_split;
+mod history;
 mod miner;
+mod pool;
+mod pool_member;
+mod referrer;
 mod round;
+mod season;
+mod season_miner;
 mod seeker;
 mod stake;
 mod treasury;
 
+pub use allowlist::*;
 pub use automation::*;
 pub use board::*;
+pub use bonus::*;
+pub use collection::*;
 pub use config::*;
+pub use fee_split::*;
+pub use history::*;
 pub use miner::*;
+pub use pool::*;
+pub use pool_member::*;
+pub use referrer::*;
 pub use round::*;
+pub use season::*;
+pub use season_miner::*;
 pub use seeker::*;
 pub use stake::*;
 pub use treasury::*;
@@ -33,6 +53,70 @@ pub enum OreAccount {
     Seeker = 107,
     Stake = 108,
     Round = 109,
+    Pool = 110,
+    PoolMember = 111,
+    Referrer = 112,
+    Allowlist = 113,
+    Collection = 114,
+    Bonus = 115,
+    Season = 116,
+    SeasonMiner = 117,
+    History = 118,
+    FeeSplit = 119,
+}
+
+/// An account whose layout has grown over time.
+///
+/// The version of an account is the length of its data. New fields are always appended, so an
+/// account is migrated by resizing it to the current layout and defaulting the appended fields.
+/// The one exception is the square arrays, which were widened to `MAX_SQUARES` when the board
+/// size became configurable (see `widen_squares`).
+pub trait Migratable: AccountDeserialize + Discriminator + Pod {
+    /// The length of the account data in the current layout, including the discriminator.
+    const SIZE: usize = 8 + std::mem::size_of::<Self>();
+
+    /// Defaults the fields appended since the given prior data length. Appended bytes are
+    /// zeroed before this is called, so only fields with a non-zero default need to be set.
+    fn migrate(&mut self, _prior_len: usize) {}
+}
+
+/// Returns true if the field at the given offset was not present in an account of the given
+/// prior data length.
+pub const fn is_appended(prior_len: usize, field_offset: usize) -> bool {
+    prior_len <= 8 + field_offset
+}
+
+/// Returns true if an account of the given prior data length was laid out with
+/// `DEFAULT_SQUARES` squares, given the offset of the field after its last square array.
+/// Legacy accounts are too short to reach that field in the current layout.
+pub const fn is_legacy_squares(prior_len: usize, next_field_offset: usize) -> bool {
+    prior_len <= 8 + next_field_offset
+}
+
+/// Widens the square arrays of an account laid out with `DEFAULT_SQUARES` squares to
+/// `MAX_SQUARES` squares, shifting the fields after each array into place. The arrays are given
+/// by their offsets in the current layout, in order. Returns the equivalent prior data length in
+/// the current layout, for defaulting appended fields.
+pub fn widen_squares(data: &mut [u8], prior_len: usize, arrays: &[usize]) -> usize {
+    let legacy = data[..prior_len - 8].to_vec();
+    data.fill(0);
+    let (mut src, mut dst) = (0, 0);
+    for &offset in arrays {
+        // Copy the fields before the array, and the legacy squares.
+        let len = offset - dst + DEFAULT_SQUARES * 8;
+        data[dst..dst + len].copy_from_slice(&legacy[src..src + len]);
+        src += len;
+        dst = offset + MAX_SQUARES * 8;
+    }
+
+    // Copy the remaining fields.
+    let len = legacy.len() - src;
+    data[dst..dst + len].copy_from_slice(&legacy[src..]);
+    prior_len + arrays.len() * (MAX_SQUARES - DEFAULT_SQUARES) * 8
+}
+
+pub fn allowlist_pda(authority: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ALLOWLIST, &authority.to_bytes()], &crate::ID)
 }
 
 pub fn automation_pda(authority: Pubkey) -> (Pubkey, u8) {
@@ -43,22 +127,68 @@ pub fn board_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[BOARD], &crate::ID)
 }
 
+pub fn bonus_pda(round_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BONUS, &round_id.to_le_bytes()], &crate::ID)
+}
+
+pub fn collection_pda(mint_authority: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COLLECTION, &mint_authority.to_bytes()], &crate::ID)
+}
+
 pub fn config_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[CONFIG], &crate::ID)
 }
 
+pub fn fee_split_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_SPLIT], &crate::ID)
+}
+
+pub fn history_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[HISTORY], &crate::ID)
+}
+
 pub fn miner_pda(authority: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[MINER, &authority.to_bytes()], &crate::ID)
 }
 
+pub fn pool_pda(operator: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POOL, &operator.to_bytes()], &crate::ID)
+}
+
+pub fn pool_member_pda(pool: Pubkey, authority: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[POOL_MEMBER, &pool.to_bytes(), &authority.to_bytes()],
+        &crate::ID,
+    )
+}
+
 pub fn seeker_pda(mint: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[SEEKER, &mint.to_bytes()], &crate::ID)
 }
 
+pub fn referrer_pda(authority: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REFERRER, &authority.to_bytes()], &crate::ID)
+}
+
 pub fn round_pda(id: u64) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[ROUND, &id.to_le_bytes()], &crate::ID)
 }
 
+pub fn season_pda(id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEASON, &id.to_le_bytes()], &crate::ID)
+}
+
+pub fn season_miner_pda(season_id: u64, authority: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEASON_MINER,
+            &season_id.to_le_bytes(),
+            &authority.to_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
 pub fn stake_pda(authority: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[STAKE, &authority.to_bytes()], &crate::ID)
 }
@@ -0,0 +1,274 @@
+use steel::*;
+
+use crate::{consts::DENOMINATOR_BPS, state::pool_pda};
+
+use super::{Migratable, OreAccount};
+
+/// Pool holds SOL deposited by many members, which its operator deploys as a single miner.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Pool {
+    /// The operator who deploys on behalf of this pool.
+    pub operator: Pubkey,
+
+    /// The fee charged by the operator on pool rewards, in basis points.
+    pub fee_bps: u64,
+
+    /// The amount of SOL available to deploy.
+    pub balance: u64,
+
+    /// The cumulative amount of SOL distributed to members, divided by the total shares at the time of distribution.
+    pub sol_rewards_factor: Numeric,
+
+    /// The cumulative amount of ORE distributed to members, divided by the total shares at the time of distribution.
+    pub ore_rewards_factor: Numeric,
+
+    /// The total shares held by members of this pool.
+    pub total_shares: u64,
+
+    /// The amount of SOL the operator has earned from fees and can claim.
+    pub operator_rewards_sol: u64,
+
+    /// The amount of ORE the operator has earned from fees and can claim.
+    pub operator_rewards_ore: u64,
+
+    /// The total amount of SOL this pool has harvested.
+    pub lifetime_rewards_sol: u64,
+
+    /// The total amount of ORE this pool has harvested.
+    pub lifetime_rewards_ore: u64,
+
+    /// The amount of SOL deployed by the pool's miner that has not been harvested yet.
+    pub deployed: u64,
+}
+
+impl Pool {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        pool_pda(self.operator)
+    }
+
+    /// Returns true if the pool has no deployed capital waiting to be harvested.
+    /// Shares are priced on the pool balance, so members may only join a settled pool.
+    pub fn is_settled(&self) -> bool {
+        self.deployed == 0
+    }
+
+    /// Deposits SOL into the pool, and returns the number of shares minted for it.
+    pub fn deposit(&mut self, amount: u64) -> u64 {
+        let shares = if self.total_shares == 0 || self.balance == 0 {
+            amount
+        } else {
+            (amount as u128 * self.total_shares as u128 / self.balance as u128)
+                .min(u64::MAX as u128) as u64
+        };
+        self.balance += amount;
+        self.total_shares += shares;
+        shares
+    }
+
+    /// Redeems shares for their pro-rata share of the pool balance, and returns the number of
+    /// shares burned and the amount of SOL paid out. Deployed capital is valued at cost, so while
+    /// capital is deployed only the part of the shares backed by the balance is burned.
+    pub fn withdraw(&mut self, shares: u64) -> (u64, u64) {
+        if shares == 0 {
+            return (0, 0);
+        }
+        let amount = if shares == self.total_shares {
+            self.balance
+        } else {
+            (shares as u128 * self.balance as u128 / self.total_shares as u128) as u64
+        };
+        let burned = if self.is_settled() {
+            shares
+        } else {
+            // Round up, so the remaining members never pay for the withdrawal.
+            let value = self.balance as u128 + self.deployed as u128;
+            (shares as u128 * self.balance as u128).div_ceil(value) as u64
+        };
+        self.balance -= amount;
+        self.total_shares -= burned;
+        (burned, amount)
+    }
+
+    /// Moves SOL from the pool balance into a round.
+    pub fn deploy(&mut self, amount: u64) {
+        self.balance -= amount;
+        self.deployed += amount;
+    }
+
+    /// Settles harvested SOL against the deployed capital. Returned principal is credited back to
+    /// the pool balance, and only the profit is distributed. Returns the SOL profit.
+    pub fn harvest(&mut self, amount_sol: u64, amount_ore: u64) -> u64 {
+        let principal = amount_sol.min(self.deployed);
+        let profit_sol = amount_sol - principal;
+        self.balance += principal;
+        self.deployed = 0;
+        self.distribute(profit_sol, amount_ore);
+        profit_sol
+    }
+
+    /// Distributes harvested rewards to members pro-rata by shares, less the operator fee.
+    pub fn distribute(&mut self, amount_sol: u64, amount_ore: u64) {
+        self.lifetime_rewards_sol += amount_sol;
+        self.lifetime_rewards_ore += amount_ore;
+
+        // Pay all rewards to the operator if the pool has no members.
+        if self.total_shares == 0 {
+            self.operator_rewards_sol += amount_sol;
+            self.operator_rewards_ore += amount_ore;
+            return;
+        }
+
+        // Charge the operator fee.
+        let fee_sol = self.fee(amount_sol);
+        let fee_ore = self.fee(amount_ore);
+        self.operator_rewards_sol += fee_sol;
+        self.operator_rewards_ore += fee_ore;
+
+        // Share the remainder with members.
+        self.sol_rewards_factor += Numeric::from_fraction(amount_sol - fee_sol, self.total_shares);
+        self.ore_rewards_factor += Numeric::from_fraction(amount_ore - fee_ore, self.total_shares);
+    }
+
+    fn fee(&self, amount: u64) -> u64 {
+        (amount as u128 * self.fee_bps as u128 / DENOMINATOR_BPS as u128) as u64
+    }
+}
+
+account!(OreAccount, Pool);
+
+impl Migratable for Pool {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_shares() {
+        let mut pool = Pool::zeroed();
+
+        // First deposits mint shares 1:1.
+        assert_eq!(pool.deposit(1_000), 1_000);
+        assert_eq!(pool.deposit(1_000), 1_000);
+
+        // Members withdraw their share of the balance.
+        assert_eq!(pool.withdraw(500), (500, 500));
+        assert_eq!(pool.withdraw(1_500), (1_500, 1_500));
+        assert_eq!(pool.balance, 0);
+        assert_eq!(pool.total_shares, 0);
+    }
+
+    #[test]
+    fn test_pool_join_deploy_harvest_leave() {
+        let mut pool = Pool::zeroed();
+        pool.fee_bps = 1_000;
+
+        // A member joins, and the operator deploys part of the balance.
+        assert_eq!(pool.deposit(1_000), 1_000);
+        pool.deploy(600);
+        assert_eq!(pool.balance, 400);
+        assert_eq!(pool.deployed, 600);
+        assert!(!pool.is_settled());
+
+        // Harvesting returns the principal to the balance, and charges the fee on profit only.
+        assert_eq!(pool.harvest(900, 50), 300);
+        assert!(pool.is_settled());
+        assert_eq!(pool.balance, 1_000);
+        assert_eq!(pool.operator_rewards_sol, 30);
+        assert_eq!(pool.operator_rewards_ore, 5);
+        assert_eq!(pool.lifetime_rewards_sol, 300);
+        assert_eq!(
+            (pool.sol_rewards_factor * Numeric::from_u64(1_000)).to_u64(),
+            270
+        );
+
+        // A member joining after the harvest pays the same price per share.
+        assert_eq!(pool.deposit(1_000), 1_000);
+
+        // The first member leaves with their principal intact.
+        assert_eq!(pool.withdraw(1_000), (1_000, 1_000));
+        assert_eq!(pool.balance, 1_000);
+        assert_eq!(pool.total_shares, 1_000);
+    }
+
+    #[test]
+    fn test_pool_harvest_loss() {
+        let mut pool = Pool::zeroed();
+        pool.fee_bps = 1_000;
+        pool.deposit(1_000);
+        pool.deposit(1_000);
+
+        // The whole balance may be deployed.
+        pool.deploy(2_000);
+        assert_eq!(pool.balance, 0);
+
+        // A losing round returns less than the principal, so there is no profit or fee.
+        assert_eq!(pool.harvest(800, 10), 0);
+        assert_eq!(pool.balance, 800);
+        assert_eq!(pool.deployed, 0);
+        assert_eq!(pool.operator_rewards_sol, 0);
+        assert_eq!(pool.operator_rewards_ore, 1);
+
+        // Members share the loss pro-rata.
+        assert_eq!(pool.withdraw(1_000), (1_000, 400));
+        assert_eq!(pool.withdraw(1_000), (1_000, 400));
+        assert_eq!(pool.balance, 0);
+    }
+
+    #[test]
+    fn test_pool_leave_while_deployed() {
+        let mut pool = Pool::zeroed();
+        pool.deposit(1_000);
+        pool.deposit(1_000);
+        pool.deploy(1_000);
+
+        // A member leaving mid-round redeems their share of the balance, and keeps the shares
+        // backing their share of the deployed capital.
+        assert_eq!(pool.withdraw(1_000), (500, 500));
+        assert_eq!(pool.balance, 500);
+        assert_eq!(pool.total_shares, 1_500);
+
+        // The remaining shares are still worth the same once the deployed capital is harvested.
+        pool.harvest(1_000, 0);
+        assert_eq!(pool.withdraw(500), (500, 500));
+        assert_eq!(pool.withdraw(1_000), (1_000, 1_000));
+        assert_eq!(pool.balance, 0);
+        assert_eq!(pool.total_shares, 0);
+    }
+
+    #[test]
+    fn test_pool_deposit_rounds_down() {
+        let mut pool = Pool::zeroed();
+        pool.deposit(1_000);
+
+        // Once shares are worth more than 1 lamport, dust deposits mint no shares.
+        pool.balance = 3_000;
+        assert_eq!(pool.deposit(2), 0);
+    }
+
+    #[test]
+    fn test_pool_distribute() {
+        let mut pool = Pool::zeroed();
+        pool.fee_bps = 1_000;
+
+        // Without members, the operator earns everything.
+        pool.distribute(100, 10);
+        assert_eq!(pool.operator_rewards_sol, 100);
+        assert_eq!(pool.operator_rewards_ore, 10);
+
+        // With members, the operator earns the fee.
+        pool.deposit(1_000);
+        pool.distribute(1_000, 100);
+        assert_eq!(pool.operator_rewards_sol, 200);
+        assert_eq!(pool.operator_rewards_ore, 20);
+        assert_eq!(
+            (pool.sol_rewards_factor * Numeric::from_u64(1_000)).to_u64(),
+            900
+        );
+        assert_eq!(
+            (pool.ore_rewards_factor * Numeric::from_u64(1_000)).to_u64(),
+            90
+        );
+        assert_eq!(pool.lifetime_rewards_sol, 1_100);
+    }
+}
@@ -0,0 +1,79 @@
+use steel::*;
+
+use crate::state::{pool_member_pda, Pool};
+
+use super::{Migratable, OreAccount};
+
+/// PoolMember tracks a member's shares and rewards in a pool.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct PoolMember {
+    /// The authority of this pool member account.
+    pub authority: Pubkey,
+
+    /// The pool this member belongs to.
+    pub pool: Pubkey,
+
+    /// The SOL rewards factor last time rewards were updated on this member account.
+    pub sol_rewards_factor: Numeric,
+
+    /// The ORE rewards factor last time rewards were updated on this member account.
+    pub ore_rewards_factor: Numeric,
+
+    /// The member's shares of the pool balance.
+    pub shares: u64,
+
+    /// The amount of SOL this member can claim.
+    pub rewards_sol: u64,
+
+    /// The amount of ORE this member can claim.
+    pub rewards_ore: u64,
+
+    /// The total amount of SOL this member has earned over its lifetime.
+    pub lifetime_rewards_sol: u64,
+
+    /// The total amount of ORE this member has earned over its lifetime.
+    pub lifetime_rewards_ore: u64,
+}
+
+impl PoolMember {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        pool_member_pda(self.pool, self.authority)
+    }
+
+    /// Claims all SOL and ORE rewards.
+    pub fn claim(&mut self, pool: &Pool) -> (u64, u64) {
+        self.update_rewards(pool);
+        let amount_sol = self.rewards_sol;
+        let amount_ore = self.rewards_ore;
+        self.rewards_sol = 0;
+        self.rewards_ore = 0;
+        (amount_sol, amount_ore)
+    }
+
+    pub fn update_rewards(&mut self, pool: &Pool) {
+        // Accumulate SOL rewards, weighted by shares.
+        if pool.sol_rewards_factor > self.sol_rewards_factor {
+            let accumulated_rewards = pool.sol_rewards_factor - self.sol_rewards_factor;
+            let personal_rewards = accumulated_rewards * Numeric::from_u64(self.shares);
+            self.rewards_sol += personal_rewards.to_u64();
+            self.lifetime_rewards_sol += personal_rewards.to_u64();
+        }
+
+        // Accumulate ORE rewards, weighted by shares.
+        if pool.ore_rewards_factor > self.ore_rewards_factor {
+            let accumulated_rewards = pool.ore_rewards_factor - self.ore_rewards_factor;
+            let personal_rewards = accumulated_rewards * Numeric::from_u64(self.shares);
+            self.rewards_ore += personal_rewards.to_u64();
+            self.lifetime_rewards_ore += personal_rewards.to_u64();
+        }
+
+        // Update this member account's last seen rewards factors.
+        self.sol_rewards_factor = pool.sol_rewards_factor;
+        self.ore_rewards_factor = pool.ore_rewards_factor;
+    }
+}
+
+account!(OreAccount, PoolMember);
+
+impl Migratable for PoolMember {}
@@ -0,0 +1,39 @@
+use steel::*;
+
+use crate::state::referrer_pda;
+
+use super::{Migratable, OreAccount};
+
+/// Referrer tracks the SOL a referrer has earned from the admin fees of referred miners.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Referrer {
+    /// The authority of this referrer account.
+    pub authority: Pubkey,
+
+    /// The amount of SOL this referrer can claim.
+    pub rewards_sol: u64,
+
+    /// The total amount of SOL this referrer has earned over its lifetime.
+    pub lifetime_rewards_sol: u64,
+
+    /// The last time this referrer claimed SOL rewards.
+    pub last_claim_at: i64,
+}
+
+impl Referrer {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        referrer_pda(self.authority)
+    }
+
+    pub fn claim_sol(&mut self, clock: &Clock) -> u64 {
+        let amount = self.rewards_sol;
+        self.rewards_sol = 0;
+        self.last_claim_at = clock.unix_timestamp;
+        amount
+    }
+}
+
+account!(OreAccount, Referrer);
+
+impl Migratable for Referrer {}
@@ -1,8 +1,12 @@
+use solana_program::keccak::hashv;
 use steel::*;
 
-use crate::state::round_pda;
+use crate::{
+    consts::{DEFAULT_SQUARES, MAX_SQUARES, MAX_WINNING_SQUARES},
+    state::round_pda,
+};
 
-use super::OreAccount;
+use super::{is_appended, is_legacy_squares, widen_squares, Migratable, OreAccount};
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
@@ -11,13 +15,13 @@ pub struct Round {
     pub id: u64,
 
     /// The amount of SOL deployed in each square.
-    pub deployed: [u64; 25],
+    pub deployed: [u64; MAX_SQUARES],
 
-    /// The hash of the end slot, provided by solana, used for random number generation.
+    /// The seed used for random number generation, derived from the hash of the sampled slot.
     pub slot_hash: [u8; 32],
 
     /// The count of miners on each square.
-    pub count: [u64; 25],
+    pub count: [u64; MAX_SQUARES],
 
     /// The slot at which claims for this round account end.
     pub expires_at: u64,
@@ -42,6 +46,109 @@ pub struct Round {
 
     /// The total amount of SOL won by miners for the round.
     pub total_winnings: u64,
+
+    /// The slot whose hash was sampled for random number generation.
+    /// This is the end slot, unless its hash had expired by the time the round was reset.
+    pub sample_slot: u64,
+
+    /// The entropy source used to derive the seed of this round.
+    pub entropy_source: u64,
+
+    /// The hash committed by the randomness authority, if the entropy source is commit-reveal.
+    pub entropy_commit: [u8; 32],
+
+    /// The secret revealed by the randomness authority at reset, if the entropy source is commit-reveal.
+    pub entropy_reveal: [u8; 32],
+
+    /// The amount of SOL paid out from the SOL motherlode.
+    pub motherlode_sol: u64,
+
+    /// The total amount of SOL deployed in the round by miners with a referrer.
+    pub total_referred: u64,
+
+    /// The amount of SOL from admin fees reserved for referrers, paid out at checkpoint.
+    pub referral_fee: u64,
+
+    /// The amount of sponsored SOL paid out to miners on the winning square.
+    pub bonus_sol: u64,
+
+    /// The amount of sponsored ORE paid out to miners on the winning square.
+    pub bonus_ore: u64,
+
+    /// The season this round is part of (0 if none).
+    pub season_id: u64,
+
+    /// The number of squares that win the round (0 for a single winning square).
+    pub winning_squares: u64,
+
+    /// The number of squares on the board for the round.
+    pub squares: u64,
+
+    /// The amount of sponsored ORE won in this round that has not been checkpointed yet.
+    /// Any remainder is rolled into the motherlode when the round is closed.
+    pub unclaimed_bonus_ore: u64,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum EntropySource {
+    SlotHash = 0,
+    CommitReveal = 1,
+}
+
+impl EntropySource {
+    pub fn from_u64(value: u64) -> Result<Self, ProgramError> {
+        u8::try_from(value)
+            .ok()
+            .and_then(|v| Self::try_from(v).ok())
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+}
+
+/// A source of entropy for selecting the winning square of a round.
+pub trait EntropyProvider {
+    /// Returns the seed for the round's random number generator, given the sampled slot hash.
+    /// If no seed can be derived, the round is refunded.
+    fn seed(&self, slot_hash: &[u8; 32]) -> Option<[u8; 32]>;
+}
+
+/// Seeds the round with the sampled slot hash.
+pub struct SlotHashEntropy;
+
+impl EntropyProvider for SlotHashEntropy {
+    fn seed(&self, slot_hash: &[u8; 32]) -> Option<[u8; 32]> {
+        Some(*slot_hash)
+    }
+}
+
+/// Seeds the round with a secret, committed by the randomness authority before the round ends,
+/// mixed with the sampled slot hash.
+pub struct CommitRevealEntropy {
+    /// The hash committed before the round ended.
+    pub commit: [u8; 32],
+
+    /// The secret revealed at reset.
+    pub reveal: [u8; 32],
+}
+
+impl CommitRevealEntropy {
+    /// Returns the commitment to a secret.
+    pub fn commitment(secret: &[u8; 32]) -> [u8; 32] {
+        hashv(&[secret]).0
+    }
+
+    pub fn is_valid(&self) -> bool {
+        Self::commitment(&self.reveal) == self.commit
+    }
+}
+
+impl EntropyProvider for CommitRevealEntropy {
+    fn seed(&self, slot_hash: &[u8; 32]) -> Option<[u8; 32]> {
+        if !self.is_valid() {
+            return None;
+        }
+        Some(hashv(&[slot_hash, &self.reveal]).0)
+    }
 }
 
 impl Round {
@@ -49,6 +156,10 @@ impl Round {
         round_pda(self.id)
     }
 
+    pub fn entropy_source(&self) -> EntropySource {
+        EntropySource::from_u64(self.entropy_source).unwrap_or(EntropySource::SlotHash)
+    }
+
     pub fn rng(&self) -> Option<u64> {
         if self.slot_hash == [0; 32] || self.slot_hash == [u8::MAX; 32] {
             return None;
@@ -61,21 +172,78 @@ impl Round {
         Some(r)
     }
 
+    /// Returns the number of squares on the board for the round.
+    pub fn num_squares(&self) -> usize {
+        match self.squares as usize {
+            0 => DEFAULT_SQUARES,
+            squares => squares.min(MAX_SQUARES),
+        }
+    }
+
+    /// Returns the primary winning square.
     pub fn winning_square(&self, rng: u64) -> usize {
-        (rng % 25) as usize
+        (rng % self.num_squares() as u64) as usize
+    }
+
+    /// Returns the number of squares that win the round. At least one square always loses.
+    pub fn num_winning_squares(&self) -> u64 {
+        let max_winning_squares = (self.num_squares() as u64 - 1).min(MAX_WINNING_SQUARES);
+        self.winning_squares.clamp(1, max_winning_squares.max(1))
+    }
+
+    /// Returns a bitmask of the winning squares. The primary winning square always wins, and any
+    /// further winning squares are drawn from the rng without replacement.
+    pub fn winning_mask(&self, rng: u64) -> u64 {
+        let mut mask = 1u64 << self.winning_square(rng);
+        let mut x = rng;
+        while (mask.count_ones() as u64) < self.num_winning_squares() {
+            x = x
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            mask |= 1u64 << ((x >> 33) % self.num_squares() as u64);
+        }
+        mask
+    }
+
+    /// Returns the total amount of SOL deployed on the winning squares.
+    pub fn winning_deployed(&self, mask: u64) -> u64 {
+        self.deployed
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| is_winning_square(mask, *i))
+            .map(|(_, d)| d)
+            .sum()
     }
 
-    pub fn top_miner_sample(&self, rng: u64, winning_square: usize) -> u64 {
-        if self.deployed[winning_square] == 0 {
+    /// Returns the total amount of SOL deployed on winning squares before the given square.
+    /// The winning squares are laid end to end for top miner sampling.
+    pub fn winning_offset(&self, mask: u64, square: usize) -> u64 {
+        self.winning_deployed(mask & ((1u64 << square) - 1))
+    }
+
+    /// Returns the number of miners on the winning squares. Miners on several winning squares
+    /// are counted once per square.
+    pub fn winning_count(&self, mask: u64) -> u64 {
+        self.count
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| is_winning_square(mask, *i))
+            .map(|(_, c)| c)
+            .sum()
+    }
+
+    pub fn top_miner_sample(&self, rng: u64, mask: u64) -> u64 {
+        let winning_deployed = self.winning_deployed(mask);
+        if winning_deployed == 0 {
             return 0;
         }
-        rng.reverse_bits() % self.deployed[winning_square]
+        rng.reverse_bits() % winning_deployed
     }
 
-    pub fn calculate_total_winnings(&self, winning_square: usize) -> u64 {
+    pub fn calculate_total_winnings(&self, mask: u64) -> u64 {
         let mut total_winnings = 0;
         for (i, &deployed) in self.deployed.iter().enumerate() {
-            if i != winning_square {
+            if !is_winning_square(mask, i) {
                 total_winnings += deployed;
             }
         }
@@ -96,10 +264,43 @@ impl Round {
     pub fn did_hit_motherlode(&self, rng: u64) -> bool {
         rng.reverse_bits() % 625 == 0
     }
+
+    /// Returns the share of the referral fee owed for a referred miner's deployment.
+    pub fn referral_share(&self, deployed: u64) -> u64 {
+        if self.total_referred == 0 {
+            return 0;
+        }
+        ((self.referral_fee as u128 * deployed as u128) / self.total_referred as u128) as u64
+    }
+}
+
+/// Returns true if the square is set in the winning mask.
+pub fn is_winning_square(mask: u64, square: usize) -> bool {
+    mask & (1u64 << square) != 0
 }
 
 account!(OreAccount, Round);
 
+impl Migratable for Round {
+    fn migrate(&mut self, prior_len: usize) {
+        // Rounds created before the board size was configurable hold the default squares.
+        let mut prior_len = prior_len;
+        if is_legacy_squares(prior_len, std::mem::offset_of!(Round, expires_at)) {
+            prior_len = widen_squares(
+                bytemuck::bytes_of_mut(self),
+                prior_len,
+                &[
+                    std::mem::offset_of!(Round, deployed),
+                    std::mem::offset_of!(Round, count),
+                ],
+            );
+        }
+        if is_appended(prior_len, std::mem::offset_of!(Round, squares)) {
+            self.squares = DEFAULT_SQUARES as u64;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use solana_program::rent::Rent;
@@ -113,4 +314,145 @@ mod tests {
         println!("required_rent: {}", required_rent);
         assert!(required_rent > 0, "Required rent should be greater than 0");
     }
+
+    #[test]
+    fn test_slot_hash_entropy() {
+        let slot_hash = [7; 32];
+        assert_eq!(SlotHashEntropy.seed(&slot_hash), Some(slot_hash));
+    }
+
+    #[test]
+    fn test_commit_reveal_entropy() {
+        let secret = [42; 32];
+        let slot_hash = [7; 32];
+        let entropy = CommitRevealEntropy {
+            commit: CommitRevealEntropy::commitment(&secret),
+            reveal: secret,
+        };
+        let seed = entropy.seed(&slot_hash).unwrap();
+        assert_ne!(seed, slot_hash);
+        assert_ne!(seed, entropy.seed(&[8; 32]).unwrap());
+
+        // A reveal which does not match the commitment yields no seed.
+        let entropy = CommitRevealEntropy {
+            commit: CommitRevealEntropy::commitment(&secret),
+            reveal: [43; 32],
+        };
+        assert_eq!(entropy.seed(&slot_hash), None);
+    }
+
+    #[test]
+    fn test_entropy_source_from_u64() {
+        assert_eq!(EntropySource::from_u64(0), Ok(EntropySource::SlotHash));
+        assert_eq!(EntropySource::from_u64(1), Ok(EntropySource::CommitReveal));
+        assert!(EntropySource::from_u64(2).is_err());
+        assert!(EntropySource::from_u64(256).is_err());
+    }
+
+    #[test]
+    fn test_winning_mask() {
+        let mut round = Round::zeroed();
+        for rng in [0, 7, 24, 25, u64::MAX, 0xdead_beef_cafe_f00d] {
+            // A single winning square matches the primary winning square.
+            round.winning_squares = 0;
+            assert_eq!(round.winning_mask(rng), 1 << round.winning_square(rng));
+
+            // Multiple winning squares are distinct, and include the primary winning square.
+            round.winning_squares = 5;
+            let mask = round.winning_mask(rng);
+            assert_eq!(mask.count_ones(), 5);
+            assert!(is_winning_square(mask, round.winning_square(rng)));
+            assert!(mask < 1 << 25);
+        }
+
+        // Winning squares respect the board size.
+        round.squares = 9;
+        round.winning_squares = MAX_WINNING_SQUARES;
+        for rng in [0, 7, 24, u64::MAX] {
+            let mask = round.winning_mask(rng);
+            assert_eq!(mask.count_ones(), 8);
+            assert!(mask < 1 << 9);
+        }
+
+        // The largest board uses every square.
+        round.squares = MAX_SQUARES as u64;
+        round.winning_squares = 1;
+        assert_eq!(
+            round.winning_square(MAX_SQUARES as u64 - 1),
+            MAX_SQUARES - 1
+        );
+    }
+
+    #[test]
+    fn test_migrate_legacy_squares() {
+        let mut expected = Round::zeroed();
+        expected.id = 42;
+        expected.slot_hash = [7; 32];
+        expected.expires_at = 1_000;
+        expected.rent_payer = Pubkey::new_unique();
+        expected.bonus_ore = 5;
+        expected.winning_squares = 1;
+        expected.squares = DEFAULT_SQUARES as u64;
+        for i in 0..DEFAULT_SQUARES {
+            expected.deployed[i] = i as u64 + 1;
+            expected.count[i] = i as u64 + 100;
+        }
+
+        // Lay out the round with the default squares, as it was before the board was resizable.
+        let bytes = bytemuck::bytes_of(&expected);
+        let deployed = std::mem::offset_of!(Round, deployed);
+        let slot_hash = std::mem::offset_of!(Round, slot_hash);
+        let count = std::mem::offset_of!(Round, count);
+        let expires_at = std::mem::offset_of!(Round, expires_at);
+        let squares = std::mem::offset_of!(Round, squares);
+        let mut legacy = vec![];
+        legacy.extend_from_slice(&bytes[..deployed + DEFAULT_SQUARES * 8]);
+        legacy.extend_from_slice(&bytes[slot_hash..count + DEFAULT_SQUARES * 8]);
+        legacy.extend_from_slice(&bytes[expires_at..squares]);
+
+        // Migrating the legacy layout restores every field.
+        let mut round = Round::zeroed();
+        bytemuck::bytes_of_mut(&mut round)[..legacy.len()].copy_from_slice(&legacy);
+        round.migrate(8 + legacy.len());
+        assert_eq!(round, expected);
+
+        // Rounds already in the current layout are not remapped.
+        let mut round = expected;
+        round.squares = 0;
+        round.migrate(8 + squares);
+        assert_eq!(round, expected);
+    }
+
+    #[test]
+    fn test_multi_square_winnings() {
+        let mut round = Round::zeroed();
+        round.deployed[1] = 100;
+        round.deployed[3] = 300;
+        round.deployed[4] = 50;
+        round.deployed[7] = 1_000;
+        let mask = (1 << 1) | (1 << 3) | (1 << 7);
+
+        // Winning squares share the SOL deployed on the other squares.
+        assert_eq!(round.winning_deployed(mask), 1_400);
+        assert_eq!(round.calculate_total_winnings(mask), 50);
+
+        // Winning squares are laid end to end for top miner sampling.
+        assert_eq!(round.winning_offset(mask, 1), 0);
+        assert_eq!(round.winning_offset(mask, 3), 100);
+        assert_eq!(round.winning_offset(mask, 7), 400);
+        assert!(round.top_miner_sample(u64::MAX, mask) < 1_400);
+        assert_eq!(round.top_miner_sample(u64::MAX, 1 << 0), 0);
+    }
+
+    #[test]
+    fn test_referral_share() {
+        let mut round = Round::zeroed();
+        assert_eq!(round.referral_share(1_000), 0);
+
+        // Referrers earn in proportion to their referees' deployed SOL.
+        round.total_referred = 4_000;
+        round.referral_fee = 20;
+        assert_eq!(round.referral_share(1_000), 5);
+        assert_eq!(round.referral_share(3_000), 15);
+    }
 }
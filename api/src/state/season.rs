@@ -0,0 +1,177 @@
+use steel::*;
+
+use crate::{
+    consts::{SEASON_LEADERBOARD_SIZE, SEASON_REWARD_WEIGHTS_BPS},
+    state::season_pda,
+};
+
+use super::{Migratable, OreAccount};
+
+/// Season tracks a range of rounds, the leaderboard of miners who won the most ORE in them,
+/// and the reward pool paid out to ranked miners once the season ends.
+/// The SOL reward is held in this account. The ORE reward is held in the treasury token account.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Season {
+    /// The season number.
+    pub id: u64,
+
+    /// The first round of the season.
+    pub start_round: u64,
+
+    /// The last round of the season.
+    pub end_round: u64,
+
+    /// The amount of SOL in the season reward pool.
+    pub reward_sol: u64,
+
+    /// The amount of ORE in the season reward pool.
+    pub reward_ore: u64,
+
+    /// The slot at which ranked miners can start claiming rewards (0 until the season ends).
+    pub claimable_at: u64,
+
+    /// The number of miners who have checkpointed a round of the season.
+    pub total_miners: u64,
+
+    /// The top miners of the season, ordered by score.
+    pub leaderboard: [LeaderboardEntry; SEASON_LEADERBOARD_SIZE],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct LeaderboardEntry {
+    /// The authority of the miner.
+    pub authority: Pubkey,
+
+    /// The amount of ORE the miner has won in the season.
+    pub score: u64,
+}
+
+impl Season {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        season_pda(self.id)
+    }
+
+    /// Returns true if the given round is part of this season.
+    pub fn contains(&self, round_id: u64) -> bool {
+        round_id >= self.start_round && round_id <= self.end_round
+    }
+
+    /// Returns the leaderboard rank of the given authority, if ranked.
+    pub fn rank(&self, authority: Pubkey) -> Option<usize> {
+        self.leaderboard
+            .iter()
+            .position(|e| e.score > 0 && e.authority == authority)
+    }
+
+    /// Records a miner's updated score, keeping the leaderboard ordered by score.
+    /// Ties are ranked in the order the score was reached.
+    pub fn update_leaderboard(&mut self, authority: Pubkey, score: u64) {
+        if score == 0 {
+            return;
+        }
+
+        // Update the miner's entry, or replace the lowest entry if the score beats it.
+        let last = SEASON_LEADERBOARD_SIZE - 1;
+        let mut i = match self.rank(authority) {
+            Some(i) => i,
+            None if score > self.leaderboard[last].score => last,
+            None => return,
+        };
+        self.leaderboard[i] = LeaderboardEntry { authority, score };
+
+        // Move the entry up the leaderboard.
+        while i > 0 && self.leaderboard[i].score > self.leaderboard[i - 1].score {
+            self.leaderboard.swap(i, i - 1);
+            i -= 1;
+        }
+    }
+
+    /// Returns the SOL and ORE rewards owed to the given rank.
+    /// The reward pool is split over the ranks that were filled, so no rewards go unclaimed.
+    pub fn rewards(&self, rank: usize) -> (u64, u64) {
+        let total_weight: u64 = self
+            .leaderboard
+            .iter()
+            .zip(SEASON_REWARD_WEIGHTS_BPS.iter())
+            .filter(|(e, _)| e.score > 0)
+            .map(|(_, w)| w)
+            .sum();
+        if total_weight == 0 {
+            return (0, 0);
+        }
+        let weight = SEASON_REWARD_WEIGHTS_BPS[rank] as u128;
+        let reward_sol = (self.reward_sol as u128 * weight / total_weight as u128) as u64;
+        let reward_ore = (self.reward_ore as u128 * weight / total_weight as u128) as u64;
+        (reward_sol, reward_ore)
+    }
+}
+
+account!(OreAccount, Season);
+
+impl Migratable for Season {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_leaderboard() {
+        let mut season = Season::zeroed();
+        let miners: Vec<Pubkey> = (0..12).map(|_| Pubkey::new_unique()).collect();
+
+        // Fill the leaderboard in ascending order of score.
+        for (i, miner) in miners.iter().enumerate().take(SEASON_LEADERBOARD_SIZE) {
+            season.update_leaderboard(*miner, (i as u64 + 1) * 100);
+        }
+        assert_eq!(season.rank(miners[9]), Some(0));
+        assert_eq!(season.rank(miners[0]), Some(9));
+
+        // A score below the lowest entry is not ranked.
+        season.update_leaderboard(miners[10], 50);
+        assert_eq!(season.rank(miners[10]), None);
+
+        // A score above the lowest entry replaces it.
+        season.update_leaderboard(miners[10], 550);
+        assert_eq!(season.rank(miners[10]), Some(5));
+        assert_eq!(season.rank(miners[0]), None);
+
+        // An improved score moves the miner up, and ties keep the earlier miner ahead.
+        season.update_leaderboard(miners[1], 1_000);
+        assert_eq!(season.rank(miners[9]), Some(0));
+        assert_eq!(season.rank(miners[1]), Some(1));
+
+        // A zero score is never ranked.
+        season.update_leaderboard(miners[11], 0);
+        assert_eq!(season.rank(miners[11]), None);
+        assert_eq!(season.rank(Pubkey::default()), None);
+    }
+
+    #[test]
+    fn test_season_rewards() {
+        let mut season = Season::zeroed();
+        season.reward_sol = 10_000;
+        season.reward_ore = 1_000;
+        assert_eq!(season.rewards(0), (0, 0));
+
+        // A single ranked miner receives the whole pool.
+        let a = Pubkey::new_unique();
+        season.update_leaderboard(a, 100);
+        assert_eq!(season.rewards(0), (10_000, 1_000));
+
+        // Rewards are split over the filled ranks by weight.
+        let b = Pubkey::new_unique();
+        season.update_leaderboard(b, 50);
+        let total = SEASON_REWARD_WEIGHTS_BPS[0] + SEASON_REWARD_WEIGHTS_BPS[1];
+        assert_eq!(
+            season.rewards(0).0,
+            10_000 * SEASON_REWARD_WEIGHTS_BPS[0] / total
+        );
+        assert_eq!(
+            season.rewards(1).0,
+            10_000 * SEASON_REWARD_WEIGHTS_BPS[1] / total
+        );
+        assert!(season.rewards(0).0 + season.rewards(1).0 <= season.reward_sol);
+    }
+}
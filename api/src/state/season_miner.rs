@@ -0,0 +1,41 @@
+use steel::*;
+
+use crate::state::season_miner_pda;
+
+use super::{Migratable, OreAccount};
+
+/// SeasonMiner tracks a miner's stats for a season, updated when the miner checkpoints.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct SeasonMiner {
+    /// The season these stats are for.
+    pub season_id: u64,
+
+    /// The authority of the miner.
+    pub authority: Pubkey,
+
+    /// The amount of SOL deployed in the season.
+    pub sol_deployed: u64,
+
+    /// The amount of SOL won in the season.
+    pub sol_won: u64,
+
+    /// The amount of ORE won in the season.
+    pub ore_won: u64,
+
+    /// The number of rounds played in the season.
+    pub rounds_played: u64,
+
+    /// Whether the miner has claimed their season rewards.
+    pub claimed: u64,
+}
+
+impl SeasonMiner {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        season_miner_pda(self.season_id, self.authority)
+    }
+}
+
+account!(OreAccount, SeasonMiner);
+
+impl Migratable for SeasonMiner {}
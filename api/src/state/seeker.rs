@@ -1,13 +1,20 @@
 use steel::*;
 
-use super::OreAccount;
+use super::{Migratable, OreAccount};
 
-/// Seeker tracks which Seeker genesis tokens have been claimed.
+/// Seeker tracks which Seeker genesis tokens, and tokens of other registered collections, have
+/// been claimed.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
 pub struct Seeker {
     // The mint address of a Seeker genesis token.
     pub mint: Pubkey,
+
+    /// The collection the token was claimed from, or the default pubkey for Seeker tokens claimed
+    /// before the collection registry.
+    pub collection: Pubkey,
 }
 
 account!(OreAccount, Seeker);
+
+impl Migratable for Seeker {}
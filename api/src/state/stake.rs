@@ -1,8 +1,11 @@
 use steel::*;
 
-use crate::state::{stake_pda, Treasury};
+use crate::{
+    consts::{DENOMINATOR_BPS, LOCK_TIER_DURATIONS, LOCK_TIER_MULTIPLIERS_BPS},
+    state::{stake_pda, Config, Treasury},
+};
 
-use super::OreAccount;
+use super::{is_appended, Migratable, OreAccount};
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
@@ -33,6 +36,36 @@ pub struct Stake {
 
     /// Flag indicating whether this staker is associated with a Solana Seeker.
     pub is_seeker: u64,
+
+    /// The SOL rewards factor last time rewards were updated on this stake account.
+    pub sol_rewards_factor: Numeric,
+
+    /// The amount of SOL this staker can claim.
+    pub rewards_sol: u64,
+
+    /// The total amount of SOL this staker has earned over its lifetime.
+    pub lifetime_rewards_sol: u64,
+
+    /// The effective reward weight of this stake account, after lock and seeker multipliers.
+    pub weight: u64,
+
+    /// The lock tier of this stake account's balance.
+    pub lock_tier: u64,
+
+    /// The timestamp when this stake account's balance unlocks.
+    pub unlock_at: i64,
+
+    /// The amount of unstaked ORE waiting to be withdrawn. Pending ORE does not earn rewards.
+    pub pending_withdrawal: u64,
+
+    /// The timestamp when the pending withdrawal can be withdrawn.
+    pub withdrawable_at: i64,
+
+    /// Flag indicating whether anyone may compound this staker's yield.
+    pub auto_compound: u64,
+
+    /// The extra reward weight multiplier granted by a collection perk, in basis points (0 for none).
+    pub collection_multiplier_bps: u64,
 }
 
 impl Stake {
@@ -40,6 +73,14 @@ impl Stake {
         stake_pda(self.authority)
     }
 
+    /// Initializes a new stake account, with reward factors starting from the current treasury.
+    pub fn initialize(&mut self, authority: Pubkey, treasury: &Treasury) {
+        *self = Stake::zeroed();
+        self.authority = authority;
+        self.rewards_factor = treasury.stake_rewards_factor;
+        self.sol_rewards_factor = treasury.stake_sol_rewards_factor;
+    }
+
     pub fn claim(&mut self, amount: u64, clock: &Clock, treasury: &Treasury) -> u64 {
         self.update_rewards(treasury);
         let amount = self.rewards.min(amount);
@@ -48,10 +89,32 @@ impl Stake {
         amount
     }
 
+    pub fn claim_sol(&mut self, amount: u64, clock: &Clock, treasury: &Treasury) -> u64 {
+        self.update_rewards(treasury);
+        let amount = self.rewards_sol.min(amount);
+        self.rewards_sol -= amount;
+        self.last_claim_at = clock.unix_timestamp;
+        amount
+    }
+
+    pub fn compound(&mut self, clock: &Clock, config: &Config, treasury: &mut Treasury) -> u64 {
+        self.update_rewards(treasury);
+        let amount = self.rewards;
+        self.rewards = 0;
+        self.balance += amount;
+        self.last_claim_at = clock.unix_timestamp;
+        self.last_deposit_at = clock.unix_timestamp;
+        self.lock(0, clock);
+        self.update_weight(config, treasury);
+        amount
+    }
+
     pub fn deposit(
         &mut self,
         amount: u64,
+        lock_tier: u64,
         clock: &Clock,
+        config: &Config,
         treasury: &mut Treasury,
         sender: &TokenAccount,
     ) -> u64 {
@@ -59,34 +122,345 @@ impl Stake {
         let amount = sender.amount().min(amount);
         self.balance += amount;
         self.last_deposit_at = clock.unix_timestamp;
-        treasury.total_staked += amount;
+        self.lock(lock_tier, clock);
+        self.update_weight(config, treasury);
         amount
     }
 
-    pub fn withdraw(&mut self, amount: u64, clock: &Clock, treasury: &mut Treasury) -> u64 {
+    /// Credits claimed mining rewards to the stake balance.
+    pub fn deposit_claimed(
+        &mut self,
+        amount: u64,
+        clock: &Clock,
+        config: &Config,
+        treasury: &mut Treasury,
+    ) -> u64 {
+        self.update_rewards(treasury);
+        self.balance += amount;
+        self.last_deposit_at = clock.unix_timestamp;
+        self.lock(0, clock);
+        self.update_weight(config, treasury);
+        amount
+    }
+
+    pub fn unstake(
+        &mut self,
+        amount: u64,
+        clock: &Clock,
+        config: &Config,
+        treasury: &mut Treasury,
+    ) -> u64 {
         self.update_rewards(treasury);
         let amount = self.balance.min(amount);
         self.balance -= amount;
+        self.pending_withdrawal += amount;
+        self.withdrawable_at = clock.unix_timestamp + config.unstake_cooldown;
+        self.expire_lock(clock);
+        self.update_weight(config, treasury);
+        amount
+    }
+
+    pub fn withdraw(&mut self, amount: u64, clock: &Clock) -> u64 {
+        let amount = self.pending_withdrawal.min(amount);
+        self.pending_withdrawal -= amount;
         self.last_withdraw_at = clock.unix_timestamp;
-        treasury.total_staked -= amount;
         amount
     }
 
+    pub fn is_cooling_down(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp < self.withdrawable_at
+    }
+
+    pub fn is_locked(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp < self.unlock_at
+    }
+
+    /// Locks the balance for the given tier. An active lock is never shortened, and ORE added to
+    /// an active lock restarts it, so new principal is locked for the full duration of its tier.
+    pub fn lock(&mut self, lock_tier: u64, clock: &Clock) {
+        self.expire_lock(clock);
+        self.lock_tier = self.lock_tier.max(lock_tier);
+        if self.lock_tier > 0 {
+            let unlock_at = clock.unix_timestamp + LOCK_TIER_DURATIONS[self.lock_tier as usize];
+            self.unlock_at = self.unlock_at.max(unlock_at);
+        }
+    }
+
+    /// Drops an expired lock, so the balance falls back to the unlocked tier.
+    pub fn expire_lock(&mut self, clock: &Clock) {
+        if !self.is_locked(clock) {
+            self.lock_tier = 0;
+        }
+    }
+
+    /// Returns the reward weight multiplier of this stake account, in basis points.
+    pub fn multiplier_bps(&self, config: &Config) -> u64 {
+        let mut multiplier_bps = LOCK_TIER_MULTIPLIERS_BPS[self.lock_tier as usize];
+        if self.collection_multiplier_bps > 0 {
+            multiplier_bps = multiplier_bps * self.collection_multiplier_bps / DENOMINATOR_BPS;
+        } else if self.is_seeker == 1 && config.seeker_multiplier_bps > 0 {
+            multiplier_bps = multiplier_bps * config.seeker_multiplier_bps / DENOMINATOR_BPS;
+        }
+        multiplier_bps
+    }
+
+    /// Drops an expired lock and recalculates the weight with the current multipliers.
+    pub fn refresh(&mut self, clock: &Clock, config: &Config, treasury: &mut Treasury) {
+        self.update_rewards(treasury);
+        self.expire_lock(clock);
+        self.update_weight(config, treasury);
+    }
+
+    /// Recalculates the effective weight of this stake account and updates the treasury total.
+    /// Rewards must be updated before calling this.
+    pub fn update_weight(&mut self, config: &Config, treasury: &mut Treasury) {
+        let weight = ((self.balance as u128 * self.multiplier_bps(config) as u128)
+            / DENOMINATOR_BPS as u128) as u64;
+        treasury.total_staked = treasury.total_staked - self.weight + weight;
+        self.weight = weight;
+    }
+
     pub fn update_rewards(&mut self, treasury: &Treasury) {
-        // Accumulate rewards, weighted by stake balance.
+        // Accumulate rewards, weighted by effective stake weight.
         if treasury.stake_rewards_factor > self.rewards_factor {
             let accumulated_rewards = treasury.stake_rewards_factor - self.rewards_factor;
             if accumulated_rewards < Numeric::ZERO {
                 panic!("Accumulated rewards is negative");
             }
-            let personal_rewards = accumulated_rewards * Numeric::from_u64(self.balance);
+            let personal_rewards = accumulated_rewards * Numeric::from_u64(self.weight);
             self.rewards += personal_rewards.to_u64();
             self.lifetime_rewards += personal_rewards.to_u64();
         }
 
+        // Accumulate SOL rewards, weighted by effective stake weight.
+        if treasury.stake_sol_rewards_factor > self.sol_rewards_factor {
+            let accumulated_rewards = treasury.stake_sol_rewards_factor - self.sol_rewards_factor;
+            if accumulated_rewards < Numeric::ZERO {
+                panic!("Accumulated rewards is negative");
+            }
+            let personal_rewards = accumulated_rewards * Numeric::from_u64(self.weight);
+            self.rewards_sol += personal_rewards.to_u64();
+            self.lifetime_rewards_sol += personal_rewards.to_u64();
+        }
+
         // Update this stake account's last seen rewards factor.
         self.rewards_factor = treasury.stake_rewards_factor;
+        self.sol_rewards_factor = treasury.stake_sol_rewards_factor;
     }
 }
 
 account!(OreAccount, Stake);
+
+impl Migratable for Stake {
+    fn migrate(&mut self, prior_len: usize) {
+        // Stake accounts created before lock tiers hold an unlocked balance, which was already
+        // counted in the treasury's total staked.
+        if is_appended(prior_len, std::mem::offset_of!(Stake, weight)) {
+            self.weight = self.balance;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::consts::{ONE_DAY, ONE_WEEK};
+
+    use super::*;
+
+    #[test]
+    fn test_update_sol_rewards() {
+        let mut treasury = Treasury::zeroed();
+        treasury.total_staked = 3_000;
+        let mut stake = Stake::zeroed();
+        stake.balance = 1_000;
+        stake.weight = 1_000;
+
+        // Share SOL with stakers.
+        treasury.share_sol_with_stakers(900);
+        stake.update_rewards(&treasury);
+        assert_eq!(stake.rewards_sol, 300);
+        assert_eq!(stake.lifetime_rewards_sol, 300);
+        assert_eq!(stake.sol_rewards_factor, treasury.stake_sol_rewards_factor);
+
+        // Updating again should not double count.
+        stake.update_rewards(&treasury);
+        assert_eq!(stake.rewards_sol, 300);
+    }
+
+    #[test]
+    fn test_lock_tier_weight() {
+        let mut config = Config::zeroed();
+        let mut treasury = Treasury::zeroed();
+        let mut stake = Stake::zeroed();
+        let mut clock = Clock::default();
+        stake.balance = 1_000;
+
+        // Lock for the longest tier.
+        stake.lock(3, &clock);
+        stake.update_weight(&config, &mut treasury);
+        assert!(stake.is_locked(&clock));
+        assert_eq!(stake.unlock_at, LOCK_TIER_DURATIONS[3]);
+        assert_eq!(stake.weight, 2_000);
+        assert_eq!(treasury.total_staked, 2_000);
+
+        // An active lock cannot be shortened.
+        stake.lock(1, &clock);
+        assert_eq!(stake.lock_tier, 3);
+        assert_eq!(stake.unlock_at, LOCK_TIER_DURATIONS[3]);
+
+        // Seekers get an extra multiplier.
+        config.seeker_multiplier_bps = 15_000;
+        stake.is_seeker = 1;
+        stake.update_weight(&config, &mut treasury);
+        assert_eq!(stake.weight, 3_000);
+        assert_eq!(treasury.total_staked, 3_000);
+
+        // A collection perk takes precedence over the Seeker multiplier.
+        stake.collection_multiplier_bps = 12_500;
+        stake.update_weight(&config, &mut treasury);
+        assert_eq!(stake.weight, 2_500);
+        assert_eq!(treasury.total_staked, 2_500);
+        stake.collection_multiplier_bps = 0;
+
+        // Once unlocked, the balance falls back to the unlocked tier.
+        clock.unix_timestamp = stake.unlock_at;
+        stake.is_seeker = 0;
+        stake.lock(0, &clock);
+        stake.update_weight(&config, &mut treasury);
+        assert!(!stake.is_locked(&clock));
+        assert_eq!(stake.weight, 1_000);
+        assert_eq!(treasury.total_staked, 1_000);
+    }
+
+    #[test]
+    fn test_refresh_expired_lock() {
+        let mut config = Config::zeroed();
+        let mut treasury = Treasury::zeroed();
+        let mut stake = Stake::zeroed();
+        let mut clock = Clock::default();
+        stake.balance = 1_000;
+        stake.lock(3, &clock);
+        stake.update_weight(&config, &mut treasury);
+        assert_eq!(stake.weight, 2_000);
+
+        // Refreshing an active lock keeps its boost.
+        stake.refresh(&clock, &config, &mut treasury);
+        assert_eq!(stake.lock_tier, 3);
+        assert_eq!(stake.weight, 2_000);
+
+        // Rewards accrue at the boosted weight until the refresh.
+        clock.unix_timestamp = stake.unlock_at;
+        treasury.stake_rewards_factor += Numeric::from_fraction(600, treasury.total_staked);
+
+        // An expired lock loses its boost.
+        stake.refresh(&clock, &config, &mut treasury);
+        assert_eq!(stake.rewards, 600);
+        assert_eq!(stake.lock_tier, 0);
+        assert_eq!(stake.weight, 1_000);
+        assert_eq!(treasury.total_staked, 1_000);
+
+        // Refreshing picks up a new Seeker multiplier.
+        stake.is_seeker = 1;
+        config.seeker_multiplier_bps = 15_000;
+        stake.refresh(&clock, &config, &mut treasury);
+        assert_eq!(stake.weight, 1_500);
+        assert_eq!(treasury.total_staked, 1_500);
+    }
+
+    #[test]
+    fn test_deposit_into_active_lock() {
+        let config = Config::zeroed();
+        let mut treasury = Treasury::zeroed();
+        let mut stake = Stake::zeroed();
+        let mut clock = Clock::default();
+        stake.balance = 1_000;
+        stake.lock(3, &clock);
+        stake.update_weight(&config, &mut treasury);
+        assert_eq!(stake.weight, 2_000);
+
+        // A tier 0 deposit the day before the lock expires is boosted, but restarts the lock.
+        clock.unix_timestamp = stake.unlock_at - ONE_DAY;
+        stake.deposit_claimed(9_000, &clock, &config, &mut treasury);
+        assert_eq!(stake.lock_tier, 3);
+        assert_eq!(
+            stake.unlock_at,
+            clock.unix_timestamp + LOCK_TIER_DURATIONS[3]
+        );
+        assert_eq!(stake.weight, 20_000);
+        assert_eq!(treasury.total_staked, 20_000);
+
+        // The boost cannot be kept past the restarted lock.
+        clock.unix_timestamp += ONE_DAY;
+        stake.refresh(&clock, &config, &mut treasury);
+        assert!(stake.is_locked(&clock));
+        assert_eq!(stake.weight, 20_000);
+        clock.unix_timestamp = stake.unlock_at;
+        stake.refresh(&clock, &config, &mut treasury);
+        assert_eq!(stake.lock_tier, 0);
+        assert_eq!(stake.weight, 10_000);
+        assert_eq!(treasury.total_staked, 10_000);
+    }
+
+    #[test]
+    fn test_unstake_cooldown() {
+        let mut config = Config::zeroed();
+        config.unstake_cooldown = ONE_WEEK;
+        let mut treasury = Treasury::zeroed();
+        let mut stake = Stake::zeroed();
+        let mut clock = Clock::default();
+        stake.balance = 1_000;
+        stake.update_weight(&config, &mut treasury);
+
+        // Unstaked ORE stops earning and waits for the cooldown.
+        assert_eq!(stake.unstake(400, &clock, &config, &mut treasury), 400);
+        assert_eq!(stake.balance, 600);
+        assert_eq!(stake.pending_withdrawal, 400);
+        assert_eq!(stake.weight, 600);
+        assert_eq!(treasury.total_staked, 600);
+        assert!(stake.is_cooling_down(&clock));
+
+        // Pending ORE can be withdrawn after the cooldown.
+        clock.unix_timestamp = ONE_WEEK;
+        assert!(!stake.is_cooling_down(&clock));
+        assert_eq!(stake.withdraw(u64::MAX, &clock), 400);
+        assert_eq!(stake.pending_withdrawal, 0);
+        assert_eq!(stake.balance, 600);
+    }
+
+    #[test]
+    fn test_compound() {
+        let config = Config::zeroed();
+        let mut treasury = Treasury::zeroed();
+        let mut stake = Stake::zeroed();
+        let clock = Clock::default();
+        stake.balance = 1_000;
+        stake.update_weight(&config, &mut treasury);
+
+        // Distribute 500 ORE to stakers.
+        treasury.stake_rewards_factor += Numeric::from_fraction(500, treasury.total_staked);
+
+        // Compound rewards into the stake balance.
+        assert_eq!(stake.compound(&clock, &config, &mut treasury), 500);
+        assert_eq!(stake.rewards, 0);
+        assert_eq!(stake.lifetime_rewards, 500);
+        assert_eq!(stake.balance, 1_500);
+        assert_eq!(stake.weight, 1_500);
+        assert_eq!(treasury.total_staked, 1_500);
+    }
+
+    #[test]
+    fn test_migrate() {
+        // Migrate a stake account created before lock tiers.
+        let mut stake = Stake::zeroed();
+        stake.balance = 1_000;
+        stake.migrate(8 + std::mem::offset_of!(Stake, sol_rewards_factor));
+        assert_eq!(stake.weight, 1_000);
+        assert_eq!(stake.lock_tier, 0);
+        assert_eq!(stake.unlock_at, 0);
+
+        // Migrating an account in the current layout is a no-op.
+        stake.weight = 1_500;
+        stake.migrate(Stake::SIZE);
+        assert_eq!(stake.weight, 1_500);
+    }
+}
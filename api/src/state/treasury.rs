@@ -1,6 +1,6 @@
 use steel::*;
 
-use super::OreAccount;
+use super::{CloseDestination, Migratable, OreAccount};
 
 /// Treasury is a singleton account which is the mint authority for the ORE token and the authority of
 /// the program's global token account.
@@ -19,7 +19,7 @@ pub struct Treasury {
     /// The cumulative ORE distributed to stakers, divided by the total stake at the time of distribution.
     pub stake_rewards_factor: Numeric,
 
-    /// The current total amount of ORE staking deposits.
+    /// The current total effective weight of ORE staking deposits.
     pub total_staked: u64,
 
     /// The current total amount of unclaimed ORE mining rewards.
@@ -27,6 +27,85 @@ pub struct Treasury {
 
     /// The current total amount of refined ORE mining rewards.
     pub total_refined: u64,
+
+    /// The amount of SOL in the motherlode rewards pool.
+    pub motherlode_sol: u64,
+
+    /// The cumulative SOL distributed to stakers, divided by the total stake at the time of distribution.
+    pub stake_sol_rewards_factor: Numeric,
+}
+
+impl Treasury {
+    /// Routes unclaimed SOL from an expired round to the given destination.
+    pub fn vault_unclaimed_sol(&mut self, amount: u64, destination: CloseDestination) {
+        match destination {
+            CloseDestination::Treasury => self.balance += amount,
+            CloseDestination::Motherlode => self.motherlode_sol += amount,
+            CloseDestination::Stakers => self.share_sol_with_stakers(amount),
+        }
+    }
+
+    /// Distributes SOL to stakers, pro-rata by stake.
+    /// If there are no stakers, the SOL is added to the treasury balance.
+    pub fn share_sol_with_stakers(&mut self, amount: u64) {
+        if self.total_staked == 0 {
+            self.balance += amount;
+            return;
+        }
+        self.stake_sol_rewards_factor += Numeric::from_fraction(amount, self.total_staked);
+    }
 }
 
 account!(OreAccount, Treasury);
+
+impl Migratable for Treasury {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn treasury(total_staked: u64) -> Treasury {
+        let mut treasury = Treasury::zeroed();
+        treasury.total_staked = total_staked;
+        treasury
+    }
+
+    #[test]
+    fn test_vault_unclaimed_sol_to_treasury() {
+        let mut treasury = treasury(1_000);
+        treasury.vault_unclaimed_sol(500, CloseDestination::Treasury);
+        assert_eq!(treasury.balance, 500);
+        assert_eq!(treasury.motherlode_sol, 0);
+        assert_eq!(treasury.stake_sol_rewards_factor, Numeric::ZERO);
+    }
+
+    #[test]
+    fn test_vault_unclaimed_sol_to_motherlode() {
+        let mut treasury = treasury(1_000);
+        treasury.vault_unclaimed_sol(500, CloseDestination::Motherlode);
+        treasury.vault_unclaimed_sol(250, CloseDestination::Motherlode);
+        assert_eq!(treasury.balance, 0);
+        assert_eq!(treasury.motherlode_sol, 750);
+        assert_eq!(treasury.stake_sol_rewards_factor, Numeric::ZERO);
+    }
+
+    #[test]
+    fn test_vault_unclaimed_sol_to_stakers() {
+        let mut treasury = treasury(1_000);
+        treasury.vault_unclaimed_sol(500, CloseDestination::Stakers);
+        assert_eq!(treasury.balance, 0);
+        assert_eq!(treasury.motherlode_sol, 0);
+        assert_eq!(
+            (treasury.stake_sol_rewards_factor * Numeric::from_u64(1_000)).to_u64(),
+            500
+        );
+    }
+
+    #[test]
+    fn test_vault_unclaimed_sol_to_stakers_without_stake() {
+        let mut treasury = treasury(0);
+        treasury.vault_unclaimed_sol(500, CloseDestination::Stakers);
+        assert_eq!(treasury.balance, 500);
+        assert_eq!(treasury.stake_sol_rewards_factor, Numeric::ZERO);
+    }
+}
@@ -44,6 +44,33 @@ async fn main() {
         "claim" => {
             claim(&rpc, &payer).await.unwrap();
         }
+        "set_claim_delegate" => {
+            set_claim_delegate(&rpc, &payer).await.unwrap();
+        }
+        "claim_referral" => {
+            claim_referral(&rpc, &payer).await.unwrap();
+        }
+        "sponsor" => {
+            sponsor(&rpc, &payer).await.unwrap();
+        }
+        "create_season" => {
+            create_season(&rpc, &payer).await.unwrap();
+        }
+        "fund_season" => {
+            fund_season(&rpc, &payer).await.unwrap();
+        }
+        "claim_season" => {
+            claim_season(&rpc, &payer).await.unwrap();
+        }
+        "season" => {
+            log_season(&rpc).await.unwrap();
+        }
+        "referrer" => {
+            log_referrer(&rpc, &payer).await.unwrap();
+        }
+        "claim_to_stake" => {
+            claim_to_stake(&rpc, &payer).await.unwrap();
+        }
         "board" => {
             log_board(&rpc).await.unwrap();
         }
@@ -77,12 +104,42 @@ async fn main() {
         "stake" => {
             log_stake(&rpc, &payer).await.unwrap();
         }
+        "create_pool" => {
+            create_pool(&rpc, &payer).await.unwrap();
+        }
+        "join_pool" => {
+            join_pool(&rpc, &payer).await.unwrap();
+        }
+        "leave_pool" => {
+            leave_pool(&rpc, &payer).await.unwrap();
+        }
+        "claim_pool" => {
+            claim_pool(&rpc, &payer).await.unwrap();
+        }
+        "harvest_pool" => {
+            harvest_pool(&rpc, &payer).await.unwrap();
+        }
+        "deploy_pool" => {
+            deploy_pool(&rpc, &payer).await.unwrap();
+        }
+        "mining_pool" => {
+            log_mining_pool(&rpc, &payer).await.unwrap();
+        }
         "deploy_all" => {
             deploy_all(&rpc, &payer).await.unwrap();
         }
         "round" => {
             log_round(&rpc).await.unwrap();
         }
+        "history" => {
+            log_history(&rpc).await.unwrap();
+        }
+        "fee_split" => {
+            log_fee_split(&rpc).await.unwrap();
+        }
+        "distribute_fees" => {
+            distribute_fees(&rpc, &payer).await.unwrap();
+        }
         "seeker" => {
             log_seeker(&rpc).await.unwrap();
         }
@@ -92,6 +149,99 @@ async fn main() {
         "set_fee_collector" => {
             set_fee_collector(&rpc, &payer).await.unwrap();
         }
+        "set_fee_split" => {
+            set_fee_split(&rpc, &payer).await.unwrap();
+        }
+        "set_entropy_source" => {
+            set_entropy_source(&rpc, &payer).await.unwrap();
+        }
+        "set_crank_bounty" => {
+            set_crank_bounty(&rpc, &payer).await.unwrap();
+        }
+        "set_close_destination" => {
+            set_close_destination(&rpc, &payer).await.unwrap();
+        }
+        "set_stake_sol_yield" => {
+            set_stake_sol_yield(&rpc, &payer).await.unwrap();
+        }
+        "set_seeker_multiplier" => {
+            set_seeker_multiplier(&rpc, &payer).await.unwrap();
+        }
+        "set_referral_fee" => {
+            set_referral_fee(&rpc, &payer).await.unwrap();
+        }
+        "set_winning_squares" => {
+            set_winning_squares(&rpc, &payer).await.unwrap();
+        }
+        "set_board_size" => {
+            set_board_size(&rpc, &payer).await.unwrap();
+        }
+        "set_deploy_limits" => {
+            set_deploy_limits(&rpc, &payer).await.unwrap();
+        }
+        "set_wrap_policy" => {
+            set_wrap_policy(&rpc, &payer).await.unwrap();
+        }
+        "import_allowlist" => {
+            import_allowlist(&rpc, &payer).await.unwrap();
+        }
+        "remove_from_allowlist" => {
+            remove_from_allowlist(&rpc, &payer).await.unwrap();
+        }
+        "set_allowlist_enabled" => {
+            set_allowlist_enabled(&rpc, &payer).await.unwrap();
+        }
+        "set_collection" => {
+            set_collection(&rpc, &payer).await.unwrap();
+        }
+        "remove_collection" => {
+            remove_collection(&rpc, &payer).await.unwrap();
+        }
+        "set_seeker_activation" => {
+            set_seeker_activation(&rpc, &payer).await.unwrap();
+        }
+        "collections" => {
+            log_collections(&rpc).await.unwrap();
+        }
+        "set_stake_claim_discount" => {
+            set_stake_claim_discount(&rpc, &payer).await.unwrap();
+        }
+        "set_unstake_cooldown" => {
+            set_unstake_cooldown(&rpc, &payer).await.unwrap();
+        }
+        "unstake" => {
+            unstake(&rpc, &payer).await.unwrap();
+        }
+        "cleanup" => {
+            cleanup(&rpc, &payer).await.unwrap();
+        }
+        "transfer" => {
+            transfer(&rpc, &payer).await.unwrap();
+        }
+        "compound" => {
+            compound(&rpc, &payer).await.unwrap();
+        }
+        "compound_all" => {
+            compound_all(&rpc, &payer).await.unwrap();
+        }
+        "refresh_stakes" => {
+            refresh_stakes(&rpc, &payer).await.unwrap();
+        }
+        "set_auto_compound" => {
+            set_auto_compound(&rpc, &payer).await.unwrap();
+        }
+        "migrate_all" => {
+            migrate_all(&rpc, &payer).await.unwrap();
+        }
+        "migrate_stakers" => {
+            migrate_stakers(&rpc, &payer).await.unwrap();
+        }
+        "claim_yield_sol" => {
+            claim_yield_sol(&rpc, &payer).await.unwrap();
+        }
+        "commit" => {
+            commit(&rpc, &payer).await.unwrap();
+        }
         "ata" => {
             ata(&rpc, &payer).await.unwrap();
         }
@@ -127,201 +277,1216 @@ async fn participating_miners(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
-async fn log_stake(
+async fn log_mining_pool(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let operator = std::env::var("OPERATOR").unwrap_or(payer.pubkey().to_string());
+    let operator = Pubkey::from_str(&operator).expect("Invalid OPERATOR");
+    let pool_address = pool_pda(operator).0;
+    let pool = get_mining_pool(rpc, operator).await?;
+    println!("Pool");
+    println!("  address: {}", pool_address);
+    println!("  operator: {}", pool.operator);
+    println!("  fee: {}%", pool.fee_bps as f64 / 100.0);
+    println!(
+        "  balance: {} SOL",
+        pool.balance as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  deployed: {} SOL",
+        pool.deployed as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!("  total_shares: {}", pool.total_shares);
+    println!(
+        "  operator_rewards_sol: {} SOL",
+        pool.operator_rewards_sol as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  operator_rewards_ore: {} ORE",
+        amount_to_ui_amount(pool.operator_rewards_ore, TOKEN_DECIMALS)
+    );
+    println!(
+        "  lifetime_rewards_sol: {} SOL",
+        pool.lifetime_rewards_sol as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  lifetime_rewards_ore: {} ORE",
+        amount_to_ui_amount(pool.lifetime_rewards_ore, TOKEN_DECIMALS)
+    );
+    let member_address = pool_member_pda(pool_address, payer.pubkey()).0;
+    if let Ok(account) = rpc.get_account(&member_address).await {
+        let mut member = *PoolMember::try_from_bytes(&account.data)?;
+        member.update_rewards(&pool);
+        println!("Member");
+        println!("  address: {}", member_address);
+        println!("  shares: {}", member.shares);
+        println!(
+            "  rewards_sol: {} SOL",
+            member.rewards_sol as f64 / LAMPORTS_PER_SOL as f64
+        );
+        println!(
+            "  rewards_ore: {} ORE",
+            amount_to_ui_amount(member.rewards_ore, TOKEN_DECIMALS)
+        );
+    }
+    Ok(())
+}
+
+async fn log_referrer(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let referrer_address = referrer_pda(authority).0;
+    let account = rpc.get_account(&referrer_address).await?;
+    let referrer = Referrer::try_from_bytes(&account.data)?;
+    println!("Referrer");
+    println!("  address: {}", referrer_address);
+    println!("  authority: {}", referrer.authority);
+    println!(
+        "  rewards_sol: {} SOL",
+        referrer.rewards_sol as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  lifetime_rewards_sol: {} SOL",
+        referrer.lifetime_rewards_sol as f64 / LAMPORTS_PER_SOL as f64
+    );
+    Ok(())
+}
+
+async fn log_stake(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let staker_address = ore_api::state::stake_pda(authority).0;
+    let mut stake = get_stake(rpc, authority).await?;
+    let treasury = get_treasury(rpc).await?;
+    stake.update_rewards(&treasury); // Include yield accrued since the last update.
+    println!("Stake");
+    println!("  address: {}", staker_address);
+    println!("  authority: {}", authority);
+    println!(
+        "  balance: {} ORE",
+        amount_to_ui_amount(stake.balance, TOKEN_DECIMALS)
+    );
+    println!("  last_claim_at: {}", stake.last_claim_at);
+    println!("  last_deposit_at: {}", stake.last_deposit_at);
+    println!("  last_withdraw_at: {}", stake.last_withdraw_at);
+    println!(
+        "  rewards_factor: {}",
+        stake.rewards_factor.to_i80f48().to_string()
+    );
+    println!(
+        "  rewards: {} ORE",
+        amount_to_ui_amount(stake.rewards, TOKEN_DECIMALS)
+    );
+    println!(
+        "  lifetime_rewards: {} ORE",
+        amount_to_ui_amount(stake.lifetime_rewards, TOKEN_DECIMALS)
+    );
+    println!(
+        "  sol_rewards_factor: {}",
+        stake.sol_rewards_factor.to_i80f48().to_string()
+    );
+    println!(
+        "  rewards_sol: {} SOL",
+        stake.rewards_sol as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  lifetime_rewards_sol: {} SOL",
+        stake.lifetime_rewards_sol as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  weight: {} ORE",
+        amount_to_ui_amount(stake.weight, TOKEN_DECIMALS)
+    );
+    println!("  lock_tier: {}", stake.lock_tier);
+    println!("  unlock_at: {}", stake.unlock_at);
+    println!(
+        "  pending_withdrawal: {} ORE",
+        amount_to_ui_amount(stake.pending_withdrawal, TOKEN_DECIMALS)
+    );
+    println!("  withdrawable_at: {}", stake.withdrawable_at);
+    println!("  auto_compound: {}", stake.auto_compound == 1);
+
+    Ok(())
+}
+
+async fn ata(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let user = pubkey!("FgZFnb3bi7QexKCdXWPwWy91eocUD7JCFySHb83vLoPD");
+    let token = pubkey!("8H8rPiWW4iTFCfEkSnf7jpqeNpFfvdH9gLouAL3Fe2Zx");
+    let ata = get_associated_token_address(&user, &token);
+    let ix = spl_associated_token_account::instruction::create_associated_token_account(
+        &payer.pubkey(),
+        &user,
+        &token,
+        &spl_token::ID,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    let account = rpc.get_account(&ata).await?;
+    println!("ATA: {}", ata);
+    println!("Account: {:?}", account);
+    Ok(())
+}
+
+async fn keys() -> Result<(), anyhow::Error> {
+    let treasury_address = ore_api::state::treasury_pda().0;
+    let config_address = ore_api::state::config_pda().0;
+    let board_address = ore_api::state::board_pda().0;
+    let address = pubkey!("pqspJ298ryBjazPAr95J9sULCVpZe3HbZTWkbC1zrkS");
+    let miner_address = ore_api::state::miner_pda(address).0;
+    println!("Treasury: {}", treasury_address);
+    println!("Config: {}", config_address);
+    println!("Board: {}", board_address);
+    println!("Miner: {}", miner_address);
+    Ok(())
+}
+
+async fn initialize(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let ix = ore_api::sdk::initialize(payer.pubkey());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn claim(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let miner = get_miner(rpc, authority).await?;
+    let recipient = miner.payout_recipient();
+    println!("Paying rewards to {}", recipient);
+    let ix_sol = ore_api::sdk::claim_sol(payer.pubkey(), authority, recipient);
+    let ix_ore = ore_api::sdk::claim_ore(payer.pubkey(), authority, recipient);
+    submit_transaction(rpc, payer, &[ix_sol, ix_ore]).await?;
+    Ok(())
+}
+
+async fn claim_referral(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let ix = ore_api::sdk::claim_referral(payer.pubkey());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn sponsor(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let board = get_board(rpc).await?;
+    let round_id = std::env::var("ID").unwrap_or(board.round_id.to_string());
+    let round_id = u64::from_str(&round_id).expect("Invalid ID");
+    if round_id < board.round_id {
+        return Err(anyhow::anyhow!("Round {} has already been reset", round_id));
+    }
+    let amount_sol = std::env::var("AMOUNT_SOL").unwrap_or("0".to_string());
+    let amount_sol = u64::from_str(&amount_sol).expect("Invalid AMOUNT_SOL");
+    let amount_ore = std::env::var("AMOUNT_ORE").unwrap_or("0".to_string());
+    let amount_ore = u64::from_str(&amount_ore).expect("Invalid AMOUNT_ORE");
+    let ix = ore_api::sdk::sponsor(payer.pubkey(), round_id, amount_sol, amount_ore);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn create_season(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let start_round = std::env::var("START_ROUND").expect("Missing START_ROUND env var");
+    let start_round = u64::from_str(&start_round).expect("Invalid START_ROUND");
+    let end_round = std::env::var("END_ROUND").expect("Missing END_ROUND env var");
+    let end_round = u64::from_str(&end_round).expect("Invalid END_ROUND");
+    let ix = ore_api::sdk::create_season(payer.pubkey(), config.season_id, start_round, end_round);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Created season {}", config.season_id + 1);
+    Ok(())
+}
+
+async fn fund_season(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let season_id = std::env::var("ID").unwrap_or(config.season_id.to_string());
+    let season_id = u64::from_str(&season_id).expect("Invalid ID");
+    let amount_sol = std::env::var("AMOUNT_SOL").unwrap_or("0".to_string());
+    let amount_sol = u64::from_str(&amount_sol).expect("Invalid AMOUNT_SOL");
+    let amount_ore = std::env::var("AMOUNT_ORE").unwrap_or("0".to_string());
+    let amount_ore = u64::from_str(&amount_ore).expect("Invalid AMOUNT_ORE");
+    let ix = ore_api::sdk::fund_season(payer.pubkey(), season_id, amount_sol, amount_ore);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn claim_season(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let season_id = std::env::var("ID").expect("Missing ID env var");
+    let season_id = u64::from_str(&season_id).expect("Invalid ID");
+    let season = get_season(rpc, season_id).await?;
+    let Some(rank) = season.rank(payer.pubkey()) else {
+        return Err(anyhow::anyhow!("Not ranked in season {}", season_id));
+    };
+    let (amount_sol, amount_ore) = season.rewards(rank);
+    println!(
+        "Rank {}: claiming {} SOL and {} ORE",
+        rank + 1,
+        amount_sol as f64 / LAMPORTS_PER_SOL as f64,
+        amount_to_ui_amount(amount_ore, TOKEN_DECIMALS)
+    );
+    let ix = ore_api::sdk::claim_season(payer.pubkey(), season_id);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_claim_delegate(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let delegate = std::env::var("DELEGATE").unwrap_or(Pubkey::default().to_string());
+    let delegate = Pubkey::from_str(&delegate).expect("Invalid DELEGATE");
+    let recipient = std::env::var("RECIPIENT").unwrap_or(Pubkey::default().to_string());
+    let recipient = Pubkey::from_str(&recipient).expect("Invalid RECIPIENT");
+    let ix = ore_api::sdk::set_claim_delegate(payer.pubkey(), delegate, recipient);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn claim_yield_sol(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let stake = get_stake(rpc, payer.pubkey()).await?;
+    let ix = ore_api::sdk::claim_yield_sol(payer.pubkey(), u64::MAX);
+    println!(
+        "Claiming up to {} SOL",
+        stake.rewards_sol as f64 / LAMPORTS_PER_SOL as f64
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn unstake(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = f64::from_str(&amount).expect("Invalid AMOUNT");
+    let amount = ui_amount_to_amount(amount, TOKEN_DECIMALS);
+    let config = get_config(rpc).await?;
+    let ix = ore_api::sdk::unstake(payer.pubkey(), amount);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Withdrawable in {} s", config.unstake_cooldown);
+    Ok(())
+}
+
+async fn compound(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let ix = ore_api::sdk::compound_yield(payer.pubkey(), authority);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn compound_all(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let treasury = get_treasury(rpc).await?;
+    let stakes = get_stakes(rpc).await?;
+    let mut ixs = vec![];
+    for (_address, mut stake) in stakes {
+        if stake.auto_compound == 1 {
+            stake.update_rewards(&treasury);
+            if stake.rewards > 0 {
+                ixs.push(ore_api::sdk::compound_yield(
+                    payer.pubkey(),
+                    stake.authority,
+                ));
+            }
+        }
+    }
+
+    // Batch and submit the instructions.
+    while !ixs.is_empty() {
+        let batch = ixs
+            .drain(..std::cmp::min(10, ixs.len()))
+            .collect::<Vec<Instruction>>();
+        submit_transaction(rpc, payer, &batch).await?;
+    }
+
+    Ok(())
+}
+
+async fn refresh_stakes(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let clock = get_clock(rpc).await?;
+    let config = get_config(rpc).await?;
+    let mut treasury = get_treasury(rpc).await?;
+    let stakes = get_stakes(rpc).await?;
+    let mut ixs = vec![];
+    for (_address, stake) in stakes {
+        let mut refreshed = stake;
+        refreshed.refresh(&clock, &config, &mut treasury);
+        if refreshed.weight != stake.weight {
+            ixs.push(ore_api::sdk::refresh_stake(payer.pubkey(), stake.authority));
+        }
+    }
+
+    // Batch and submit the instructions.
+    while !ixs.is_empty() {
+        let batch = ixs
+            .drain(..std::cmp::min(10, ixs.len()))
+            .collect::<Vec<Instruction>>();
+        submit_transaction(rpc, payer, &batch).await?;
+    }
+
+    Ok(())
+}
+
+async fn migrate_all(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let account = std::env::var("ACCOUNT").expect("Missing ACCOUNT env var");
+    let (discriminator, size) = match account.as_str() {
+        "allowlist" => (Allowlist::discriminator(), Allowlist::SIZE),
+        "automation" => (Automation::discriminator(), Automation::SIZE),
+        "board" => (Board::discriminator(), Board::SIZE),
+        "bonus" => (Bonus::discriminator(), Bonus::SIZE),
+        "collection" => (Collection::discriminator(), Collection::SIZE),
+        "config" => (Config::discriminator(), Config::SIZE),
+        "fee_split" => (FeeSplit::discriminator(), FeeSplit::SIZE),
+        "history" => (History::discriminator(), History::SIZE),
+        "miner" => (Miner::discriminator(), Miner::SIZE),
+        "pool" => (
+            ore_api::state::Pool::discriminator(),
+            ore_api::state::Pool::SIZE,
+        ),
+        "pool_member" => (PoolMember::discriminator(), PoolMember::SIZE),
+        "referrer" => (Referrer::discriminator(), Referrer::SIZE),
+        "round" => (Round::discriminator(), Round::SIZE),
+        "season" => (Season::discriminator(), Season::SIZE),
+        "season_miner" => (SeasonMiner::discriminator(), SeasonMiner::SIZE),
+        "seeker" => (Seeker::discriminator(), Seeker::SIZE),
+        "stake" => (Stake::discriminator(), Stake::SIZE),
+        "treasury" => (Treasury::discriminator(), Treasury::SIZE),
+        _ => panic!("Invalid ACCOUNT"),
+    };
+    let outdated = get_outdated_accounts(rpc, discriminator, size).await?;
+    println!("Migrating {} {} accounts", outdated.len(), account);
+    let mut ixs = outdated
+        .into_iter()
+        .map(|address| ore_api::sdk::migrate(payer.pubkey(), address))
+        .collect::<Vec<Instruction>>();
+
+    // Batch and submit the instructions.
+    while !ixs.is_empty() {
+        let batch = ixs
+            .drain(..std::cmp::min(10, ixs.len()))
+            .collect::<Vec<Instruction>>();
+        submit_transaction(rpc, payer, &batch).await?;
+    }
+
+    Ok(())
+}
+
+async fn migrate_stakers(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let outdated = get_outdated_accounts(rpc, Stake::discriminator(), Stake::SIZE).await?;
+    let mut ixs = vec![];
+    for address in outdated {
+        // Legacy stake accounts hold their ORE in the treasury, and have no token account.
+        let data = rpc.get_account_data(&address).await?;
+        let authority = Pubkey::try_from(&data[8..40]).unwrap();
+        let stake_tokens_address = get_associated_token_address(&address, &MINT_ADDRESS);
+        if rpc.get_account(&stake_tokens_address).await.is_err() {
+            println!("Migrating staker: {}", authority);
+            ixs.push(ore_api::sdk::migrate_staker(payer.pubkey(), authority));
+        } else {
+            ixs.push(ore_api::sdk::migrate(payer.pubkey(), address));
+        }
+    }
+
+    // Batch and submit the instructions.
+    while !ixs.is_empty() {
+        let batch = ixs
+            .drain(..std::cmp::min(5, ixs.len()))
+            .collect::<Vec<Instruction>>();
+        submit_transaction(rpc, payer, &batch).await?;
+    }
+
+    Ok(())
+}
+
+async fn set_auto_compound(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let auto_compound = std::env::var("AUTO_COMPOUND").expect("Missing AUTO_COMPOUND env var");
+    let auto_compound = bool::from_str(&auto_compound).expect("Invalid AUTO_COMPOUND");
+    let ix = ore_api::sdk::set_auto_compound(payer.pubkey(), auto_compound);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn claim_to_stake(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    println!(
+        "Claim fee discount: {}%",
+        config.stake_claim_fee_discount_bps as f64 / 100.0
+    );
+    let miner = get_miner(rpc, payer.pubkey()).await?;
+    let ix_sol = ore_api::sdk::claim_sol(payer.pubkey(), payer.pubkey(), miner.payout_recipient());
+    let ix_ore = ore_api::sdk::claim_ore_to_stake(payer.pubkey());
+    submit_transaction(rpc, payer, &[ix_sol, ix_ore]).await?;
+    Ok(())
+}
+
+async fn cleanup(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mut ixs = vec![];
+
+    // Close automation.
+    let automation_address = automation_pda(payer.pubkey()).0;
+    let has_automation = rpc.get_account(&automation_address).await.is_ok();
+    if has_automation {
+        println!("Closing automation: {}", automation_address);
+        ixs.push(ore_api::sdk::automate(
+            payer.pubkey(),
+            0,
+            0,
+            Pubkey::default(),
+            0,
+            0,
+            0,
+        ));
+    }
+
+    // Close miner.
+    if let Ok(miner) = get_miner(rpc, payer.pubkey()).await {
+        if miner.checkpoint_id != miner.round_id {
+            println!("Miner has not checkpointed round {}", miner.round_id);
+        } else if miner.rewards_sol > 0 || miner.rewards_ore > 0 || miner.refined_ore > 0 {
+            println!("Miner has unclaimed rewards");
+        } else {
+            println!("Closing miner: {}", miner_pda(payer.pubkey()).0);
+            ixs.push(ore_api::sdk::close_miner(payer.pubkey()));
+        }
+    }
+
+    // Close stake.
+    if let Ok(mut stake) = get_stake(rpc, payer.pubkey()).await {
+        let treasury = get_treasury(rpc).await?;
+        stake.update_rewards(&treasury);
+        if stake.balance > 0 || stake.pending_withdrawal > 0 {
+            println!("Stake has a balance");
+        } else if stake.rewards > 0 || stake.rewards_sol > 0 {
+            println!("Stake has unclaimed rewards");
+        } else {
+            println!("Closing stake: {}", stake_pda(payer.pubkey()).0);
+            ixs.push(ore_api::sdk::close_stake(payer.pubkey()));
+        }
+    }
+
+    if ixs.is_empty() {
+        println!("Nothing to close");
+        return Ok(());
+    }
+    submit_transaction(rpc, payer, &ixs).await?;
+    Ok(())
+}
+
+async fn transfer(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let new_authority =
+        read_keypair_file(&std::env::var("NEW_KEYPAIR").expect("Missing NEW_KEYPAIR env var"))
+            .unwrap();
+    let mut ixs = vec![];
+    if let Ok(miner) = get_miner(rpc, payer.pubkey()).await {
+        if miner.checkpoint_id != miner.round_id {
+            ixs.push(ore_api::sdk::checkpoint(
+                payer.pubkey(),
+                payer.pubkey(),
+                miner.round_id,
+                get_round_season_id(rpc, miner.round_id).await,
+                miner.referrer,
+            ));
+        }
+        ixs.push(ore_api::sdk::transfer_miner(
+            payer.pubkey(),
+            new_authority.pubkey(),
+            get_config(rpc).await?.season_id,
+        ));
+    }
+    if get_stake(rpc, payer.pubkey()).await.is_ok() {
+        ixs.push(ore_api::sdk::transfer_stake(
+            payer.pubkey(),
+            new_authority.pubkey(),
+        ));
+    }
+    if ixs.is_empty() {
+        println!("Nothing to transfer");
+        return Ok(());
+    }
+
+    // Both the old and new authority must sign.
+    let blockhash = rpc.get_latest_blockhash().await?;
+    let mut all_instructions = vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+        ComputeBudgetInstruction::set_compute_unit_price(1_000_000),
+    ];
+    all_instructions.extend_from_slice(&ixs);
+    let transaction = Transaction::new_signed_with_payer(
+        &all_instructions,
+        Some(&payer.pubkey()),
+        &[payer, &new_authority],
+        blockhash,
+    );
+    let signature = rpc.send_and_confirm_transaction(&transaction).await?;
+    println!("Transaction submitted: {:?}", signature);
+    println!("Transferred to {}", new_authority.pubkey());
+    Ok(())
+}
+
+async fn bury(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount_str = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount_f64 = f64::from_str(&amount_str).expect("Invalid AMOUNT");
+    let amount_u64 = ui_amount_to_amount(amount_f64, TOKEN_DECIMALS);
+    // Bury wraps treasury SOL itself, up to the configured cap by default.
+    let wrap_amount = match std::env::var("WRAP_AMOUNT") {
+        Ok(wrap_amount) => u64::from_str(&wrap_amount).expect("Invalid WRAP_AMOUNT"),
+        Err(_) => get_config(rpc).await?.max_wrap,
+    };
+    let bury_ix = ore_api::sdk::bury(payer.pubkey(), amount_u64, wrap_amount);
+    // submit_transaction(rpc, payer, &[bury_ix]).await?;
+    simulate_transaction(rpc, payer, &[bury_ix]).await;
+    Ok(())
+}
+
+async fn reset(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let board = get_board(rpc).await?;
+    let config = get_config(rpc).await?;
+    let round = get_round(rpc, board.round_id).await?;
+    let slot_hashes = get_slot_hashes(rpc).await?;
+    let max_slot = board.end_slot + SLOT_HASH_FALLBACK_SLOTS;
+    if let Some((slot, slot_hash)) = slot_hashes
+        .iter()
+        .rev()
+        .find(|(slot, _)| *slot >= board.end_slot && *slot <= max_slot)
+    {
+        let id = get_winning_square(&slot_hash.to_bytes(), round.num_squares() as u64);
+        // let square = get_square(rpc).await?;
+        println!("Sample slot: {}", slot);
+        println!("Winning square: {}", id);
+        // println!("Miners: {:?}", square.miners);
+        // miners = square.miners[id as usize].to_vec();
+    };
+    let treasury = get_treasury(rpc).await?;
+    let bounty = config.reset_bounty.min(treasury.balance);
+    println!("Bounty: {} SOL", bounty as f64 / LAMPORTS_PER_SOL as f64);
+    let reveal = if round.entropy_source() == EntropySource::CommitReveal
+        && config.randomness_authority == payer.pubkey()
+    {
+        entropy_secret(payer, board.round_id)
+    } else {
+        [0; 32]
+    };
+    let sponsor = get_bonus(rpc, board.round_id)
+        .await
+        .map_or(payer.pubkey(), |bonus| bonus.sponsor);
+    let reset_ix = ore_api::sdk::reset(
+        payer.pubkey(),
+        config.fee_collector,
+        board.round_id,
+        config.season_id,
+        sponsor,
+        Pubkey::default(),
+        reveal,
+    );
+    // simulate_transaction(rpc, payer, &[reset_ix]).await;
+    submit_transaction(rpc, payer, &[reset_ix]).await?;
+    Ok(())
+}
+
+async fn commit(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let board = get_board(rpc).await?;
+    let secret = entropy_secret(payer, board.round_id);
+    let commit = CommitRevealEntropy::commitment(&secret);
+    let ix = ore_api::sdk::commit(payer.pubkey(), board.round_id, commit);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+/// Derives the entropy secret for a round from the randomness authority's signature.
+fn entropy_secret(authority: &solana_sdk::signer::keypair::Keypair, round_id: u64) -> [u8; 32] {
+    let signature = authority.sign_message(&round_id.to_le_bytes());
+    solana_program::keccak::hashv(&[signature.as_ref()]).0
+}
+
+async fn deploy(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let square_id = std::env::var("SQUARE").expect("Missing SQUARE env var");
+    let square_id = u64::from_str(&square_id).expect("Invalid SQUARE");
+    
+    // Check balance before deploying
+    let balance = rpc.get_balance(&payer.pubkey()).await?;
+    let min_balance_needed = amount + 10_000 + 2_000_000 + 890_880; // deploy + checkpoint + fees + rent-exempt
+    
+    if balance < min_balance_needed {
+        eprintln!("❌ Insufficient balance");
+        eprintln!("   Current balance: {} SOL ({} lamports)", balance as f64 / 1_000_000_000.0, balance);
+        eprintln!("   Minimum needed: {} SOL ({} lamports)", min_balance_needed as f64 / 1_000_000_000.0, min_balance_needed);
+        eprintln!("   Deposit at least {} SOL to address: {}", (min_balance_needed - balance) as f64 / 1_000_000_000.0, payer.pubkey());
+        return Err(anyhow::anyhow!("Insufficient balance"));
+    }
+    
+    let board = get_board(rpc).await?;
+    let round = get_round(rpc, board.round_id).await?;
+    check_deploy_limits(rpc, payer.pubkey(), board.round_id, amount, 1).await?;
+    let referrer = std::env::var("REFERRER").unwrap_or(Pubkey::default().to_string());
+    let referrer = Pubkey::from_str(&referrer).expect("Invalid REFERRER");
+    let mut squares = vec![false; round.num_squares()];
+    squares[square_id as usize] = true;
+    let ix = ore_api::sdk::deploy(
+        payer.pubkey(),
+        payer.pubkey(),
+        amount,
+        board.round_id,
+        round.season_id,
+        &squares,
+        referrer,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn create_pool(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let fee_bps = std::env::var("FEE_BPS").expect("Missing FEE_BPS env var");
+    let fee_bps = u64::from_str(&fee_bps).expect("Invalid FEE_BPS");
+    if fee_bps > MAX_POOL_FEE_BPS {
+        return Err(anyhow::anyhow!(
+            "Pool fee exceeds maximum of {} bps",
+            MAX_POOL_FEE_BPS
+        ));
+    }
+    let ix = ore_api::sdk::create_pool(payer.pubkey(), fee_bps);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Pool: {}", pool_pda(payer.pubkey()).0);
+    Ok(())
+}
+
+async fn join_pool(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let operator = std::env::var("OPERATOR").expect("Missing OPERATOR env var");
+    let operator = Pubkey::from_str(&operator).expect("Invalid OPERATOR");
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let ix = ore_api::sdk::join_pool(payer.pubkey(), operator, amount);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn leave_pool(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let operator = std::env::var("OPERATOR").expect("Missing OPERATOR env var");
+    let operator = Pubkey::from_str(&operator).expect("Invalid OPERATOR");
+    let ix = ore_api::sdk::leave_pool(payer.pubkey(), operator);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn claim_pool(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let operator = std::env::var("OPERATOR").unwrap_or(payer.pubkey().to_string());
+    let operator = Pubkey::from_str(&operator).expect("Invalid OPERATOR");
+    let ix = ore_api::sdk::claim_pool(payer.pubkey(), operator);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn harvest_pool(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let operator = std::env::var("OPERATOR").unwrap_or(payer.pubkey().to_string());
+    let operator = Pubkey::from_str(&operator).expect("Invalid OPERATOR");
+    let pool_address = pool_pda(operator).0;
+    let miner = get_miner(rpc, pool_address).await?;
+    let season_id = get_round_season_id(rpc, miner.round_id).await;
+    let checkpoint_ix = ore_api::sdk::checkpoint(
+        payer.pubkey(),
+        pool_address,
+        miner.round_id,
+        season_id,
+        miner.referrer,
+    );
+    let harvest_ix = ore_api::sdk::harvest_pool(payer.pubkey(), operator);
+    submit_transaction(rpc, payer, &[checkpoint_ix, harvest_ix]).await?;
+    Ok(())
+}
+
+async fn deploy_pool(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let square_id = std::env::var("SQUARE").expect("Missing SQUARE env var");
+    let square_id = u64::from_str(&square_id).expect("Invalid SQUARE");
+    let pool_address = pool_pda(payer.pubkey()).0;
+    let pool = get_mining_pool(rpc, payer.pubkey()).await?;
+    if amount > pool.balance {
+        return Err(anyhow::anyhow!("Pool balance too low"));
+    }
+    let board = get_board(rpc).await?;
+    let round = get_round(rpc, board.round_id).await?;
+    check_deploy_limits(rpc, pool_address, board.round_id, amount, 1).await?;
+    let mut squares = vec![false; round.num_squares()];
+    squares[square_id as usize] = true;
+    let ix = ore_api::sdk::deploy(
+        payer.pubkey(),
+        pool_address,
+        amount,
+        board.round_id,
+        round.season_id,
+        &squares,
+        Pubkey::default(),
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn deploy_all(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let board = get_board(rpc).await?;
+    let round = get_round(rpc, board.round_id).await?;
+    let num_squares = round.num_squares();
+    check_deploy_limits(
+        rpc,
+        payer.pubkey(),
+        board.round_id,
+        amount,
+        num_squares as u64,
+    )
+    .await?;
+    let squares = vec![true; num_squares];
+    let ix = ore_api::sdk::deploy(
+        payer.pubkey(),
+        payer.pubkey(),
+        amount,
+        board.round_id,
+        round.season_id,
+        &squares,
+        Pubkey::default(),
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+/// Checks a deployment against the configured deploy limits before submitting it.
+async fn check_deploy_limits(
+    rpc: &RpcClient,
+    authority: Pubkey,
+    round_id: u64,
+    amount: u64,
+    num_squares: u64,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    if amount < config.min_deploy_amount {
+        return Err(anyhow::anyhow!(
+            "Amount is below the minimum deploy of {} SOL",
+            config.min_deploy_amount as f64 / LAMPORTS_PER_SOL as f64
+        ));
+    }
+    if config.max_deploy_per_square > 0 && amount > config.max_deploy_per_square {
+        return Err(anyhow::anyhow!(
+            "Amount exceeds the per-square limit of {} SOL",
+            config.max_deploy_per_square as f64 / LAMPORTS_PER_SOL as f64
+        ));
+    }
+    if config.max_deploy_per_round > 0 {
+        let deployed = match get_miner(rpc, authority).await {
+            Ok(miner) if miner.round_id == round_id => miner.deployed.iter().sum::<u64>(),
+            _ => 0,
+        };
+        if deployed + amount * num_squares > config.max_deploy_per_round {
+            return Err(anyhow::anyhow!(
+                "Deploy exceeds the per-round limit of {} SOL ({} SOL already deployed)",
+                config.max_deploy_per_round as f64 / LAMPORTS_PER_SOL as f64,
+                deployed as f64 / LAMPORTS_PER_SOL as f64
+            ));
+        }
+    }
+    Ok(())
+}
+
+async fn claim_seeker(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let seeker_mint = pubkey!("5mXbkqKz883aufhAsx3p5Z1NcvD2ppZbdTTznM6oUKLj");
+    let mint = std::env::var("MINT").unwrap_or(seeker_mint.to_string());
+    let mint = Pubkey::from_str(&mint).expect("Invalid MINT");
+    let mint_authority =
+        std::env::var("MINT_AUTHORITY").unwrap_or(SEEKER_MINT_AUTHORITY.to_string());
+    let mint_authority = Pubkey::from_str(&mint_authority).expect("Invalid MINT_AUTHORITY");
+    let token_program = rpc.get_account(&mint).await?.owner;
+    let ix = ore_api::sdk::claim_seeker(payer.pubkey(), mint, mint_authority, token_program);
+    // submit_transaction(rpc, payer, &[ix]).await?;
+    simulate_transaction(rpc, payer, &[ix]).await;
+    Ok(())
+}
+
+async fn set_admin(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let ix = ore_api::sdk::set_admin(payer.pubkey(), payer.pubkey());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_entropy_source(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let entropy_source = std::env::var("ENTROPY_SOURCE").expect("Missing ENTROPY_SOURCE env var");
+    let entropy_source = match entropy_source.as_str() {
+        "slot_hash" => EntropySource::SlotHash,
+        "commit_reveal" => EntropySource::CommitReveal,
+        _ => panic!("Invalid ENTROPY_SOURCE"),
+    };
+    let randomness_authority =
+        std::env::var("RANDOMNESS_AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let randomness_authority =
+        Pubkey::from_str(&randomness_authority).expect("Invalid RANDOMNESS_AUTHORITY");
+    let ix = ore_api::sdk::set_entropy_source(payer.pubkey(), entropy_source, randomness_authority);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_crank_bounty(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let reset_bounty = std::env::var("RESET_BOUNTY").expect("Missing RESET_BOUNTY env var");
+    let reset_bounty = u64::from_str(&reset_bounty).expect("Invalid RESET_BOUNTY");
+    let close_bounty = std::env::var("CLOSE_BOUNTY").expect("Missing CLOSE_BOUNTY env var");
+    let close_bounty = u64::from_str(&close_bounty).expect("Invalid CLOSE_BOUNTY");
+    if reset_bounty > MAX_CRANK_BOUNTY || close_bounty > MAX_CRANK_BOUNTY {
+        return Err(anyhow::anyhow!(
+            "Bounty exceeds maximum of {} lamports",
+            MAX_CRANK_BOUNTY
+        ));
+    }
+    let ix = ore_api::sdk::set_crank_bounty(payer.pubkey(), reset_bounty, close_bounty);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_close_destination(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let close_destination =
+        std::env::var("CLOSE_DESTINATION").expect("Missing CLOSE_DESTINATION env var");
+    let close_destination = match close_destination.as_str() {
+        "treasury" => CloseDestination::Treasury,
+        "motherlode" => CloseDestination::Motherlode,
+        "stakers" => CloseDestination::Stakers,
+        _ => panic!("Invalid CLOSE_DESTINATION"),
+    };
+    let ix = ore_api::sdk::set_close_destination(payer.pubkey(), close_destination);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_stake_sol_yield(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let admin_fee_bps = std::env::var("ADMIN_FEE_BPS").expect("Missing ADMIN_FEE_BPS env var");
+    let admin_fee_bps = u64::from_str(&admin_fee_bps).expect("Invalid ADMIN_FEE_BPS");
+    let vault_bps = std::env::var("VAULT_BPS").expect("Missing VAULT_BPS env var");
+    let vault_bps = u64::from_str(&vault_bps).expect("Invalid VAULT_BPS");
+    let ix = ore_api::sdk::set_stake_sol_yield(payer.pubkey(), admin_fee_bps, vault_bps);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_seeker_multiplier(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
-    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
-    let staker_address = ore_api::state::stake_pda(authority).0;
-    let stake = get_stake(rpc, authority).await?;
-    println!("Stake");
-    println!("  address: {}", staker_address);
-    println!("  authority: {}", authority);
-    println!(
-        "  balance: {} ORE",
-        amount_to_ui_amount(stake.balance, TOKEN_DECIMALS)
-    );
-    println!("  last_claim_at: {}", stake.last_claim_at);
-    println!("  last_deposit_at: {}", stake.last_deposit_at);
-    println!("  last_withdraw_at: {}", stake.last_withdraw_at);
-    println!(
-        "  rewards_factor: {}",
-        stake.rewards_factor.to_i80f48().to_string()
-    );
-    println!(
-        "  rewards: {} ORE",
-        amount_to_ui_amount(stake.rewards, TOKEN_DECIMALS)
-    );
-    println!(
-        "  lifetime_rewards: {} ORE",
-        amount_to_ui_amount(stake.lifetime_rewards, TOKEN_DECIMALS)
-    );
-
+    let seeker_multiplier_bps =
+        std::env::var("SEEKER_MULTIPLIER_BPS").expect("Missing SEEKER_MULTIPLIER_BPS env var");
+    let seeker_multiplier_bps =
+        u64::from_str(&seeker_multiplier_bps).expect("Invalid SEEKER_MULTIPLIER_BPS");
+    let ix = ore_api::sdk::set_seeker_multiplier(payer.pubkey(), seeker_multiplier_bps);
+    submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn ata(
+async fn set_unstake_cooldown(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let user = pubkey!("FgZFnb3bi7QexKCdXWPwWy91eocUD7JCFySHb83vLoPD");
-    let token = pubkey!("8H8rPiWW4iTFCfEkSnf7jpqeNpFfvdH9gLouAL3Fe2Zx");
-    let ata = get_associated_token_address(&user, &token);
-    let ix = spl_associated_token_account::instruction::create_associated_token_account(
-        &payer.pubkey(),
-        &user,
-        &token,
-        &spl_token::ID,
-    );
+    let unstake_cooldown =
+        std::env::var("UNSTAKE_COOLDOWN").expect("Missing UNSTAKE_COOLDOWN env var");
+    let unstake_cooldown = i64::from_str(&unstake_cooldown).expect("Invalid UNSTAKE_COOLDOWN");
+    let ix = ore_api::sdk::set_unstake_cooldown(payer.pubkey(), unstake_cooldown);
     submit_transaction(rpc, payer, &[ix]).await?;
-    let account = rpc.get_account(&ata).await?;
-    println!("ATA: {}", ata);
-    println!("Account: {:?}", account);
     Ok(())
 }
 
-async fn keys() -> Result<(), anyhow::Error> {
-    let treasury_address = ore_api::state::treasury_pda().0;
-    let config_address = ore_api::state::config_pda().0;
-    let board_address = ore_api::state::board_pda().0;
-    let address = pubkey!("pqspJ298ryBjazPAr95J9sULCVpZe3HbZTWkbC1zrkS");
-    let miner_address = ore_api::state::miner_pda(address).0;
-    println!("Treasury: {}", treasury_address);
-    println!("Config: {}", config_address);
-    println!("Board: {}", board_address);
-    println!("Miner: {}", miner_address);
+async fn set_stake_claim_discount(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let discount_bps = std::env::var("DISCOUNT_BPS").expect("Missing DISCOUNT_BPS env var");
+    let discount_bps = u64::from_str(&discount_bps).expect("Invalid DISCOUNT_BPS");
+    let ix = ore_api::sdk::set_stake_claim_discount(payer.pubkey(), discount_bps);
+    submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn initialize(
+async fn set_referral_fee(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let ix = ore_api::sdk::initialize(payer.pubkey());
+    let referral_fee_bps =
+        std::env::var("REFERRAL_FEE_BPS").expect("Missing REFERRAL_FEE_BPS env var");
+    let referral_fee_bps = u64::from_str(&referral_fee_bps).expect("Invalid REFERRAL_FEE_BPS");
+    let ix = ore_api::sdk::set_referral_fee(payer.pubkey(), referral_fee_bps);
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn claim(
+async fn set_winning_squares(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let ix_sol = ore_api::sdk::claim_sol(payer.pubkey());
-    let ix_ore = ore_api::sdk::claim_ore(payer.pubkey());
-    submit_transaction(rpc, payer, &[ix_sol, ix_ore]).await?;
+    let winning_squares =
+        std::env::var("WINNING_SQUARES").expect("Missing WINNING_SQUARES env var");
+    let winning_squares = u64::from_str(&winning_squares).expect("Invalid WINNING_SQUARES");
+    let ix = ore_api::sdk::set_winning_squares(payer.pubkey(), winning_squares);
+    submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn bury(
+async fn set_board_size(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let amount_str = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
-    let amount_f64 = f64::from_str(&amount_str).expect("Invalid AMOUNT");
-    let amount_u64 = ui_amount_to_amount(amount_f64, TOKEN_DECIMALS);
-    let wrap_ix = ore_api::sdk::wrap(payer.pubkey());
-    let bury_ix = ore_api::sdk::bury(payer.pubkey(), amount_u64);
-    // submit_transaction(rpc, payer, &[wrap_ix, bury_ix]).await?;
-    simulate_transaction(rpc, payer, &[wrap_ix, bury_ix]).await;
+    let squares = std::env::var("SQUARES").expect("Missing SQUARES env var");
+    let squares = u64::from_str(&squares).expect("Invalid SQUARES");
+    let ix = ore_api::sdk::set_board_size(payer.pubkey(), squares);
+    submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn reset(
+async fn set_deploy_limits(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let board = get_board(rpc).await?;
-    let config = get_config(rpc).await?;
-    let slot_hashes = get_slot_hashes(rpc).await?;
-    if let Some(slot_hash) = slot_hashes.get(&board.end_slot) {
-        let id = get_winning_square(&slot_hash.to_bytes());
-        // let square = get_square(rpc).await?;
-        println!("Winning square: {}", id);
-        // println!("Miners: {:?}", square.miners);
-        // miners = square.miners[id as usize].to_vec();
-    };
-    let reset_ix = ore_api::sdk::reset(
+    let min_deploy_amount =
+        std::env::var("MIN_DEPLOY_AMOUNT").expect("Missing MIN_DEPLOY_AMOUNT env var");
+    let min_deploy_amount = u64::from_str(&min_deploy_amount).expect("Invalid MIN_DEPLOY_AMOUNT");
+    let max_deploy_per_round =
+        std::env::var("MAX_DEPLOY_PER_ROUND").expect("Missing MAX_DEPLOY_PER_ROUND env var");
+    let max_deploy_per_round =
+        u64::from_str(&max_deploy_per_round).expect("Invalid MAX_DEPLOY_PER_ROUND");
+    let max_deploy_per_square =
+        std::env::var("MAX_DEPLOY_PER_SQUARE").expect("Missing MAX_DEPLOY_PER_SQUARE env var");
+    let max_deploy_per_square =
+        u64::from_str(&max_deploy_per_square).expect("Invalid MAX_DEPLOY_PER_SQUARE");
+    let ix = ore_api::sdk::set_deploy_limits(
         payer.pubkey(),
-        config.fee_collector,
-        board.round_id,
-        Pubkey::default(),
+        min_deploy_amount,
+        max_deploy_per_round,
+        max_deploy_per_square,
     );
-    // simulate_transaction(rpc, payer, &[reset_ix]).await;
-    submit_transaction(rpc, payer, &[reset_ix]).await?;
+    submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn deploy(
+async fn set_wrap_policy(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
-    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
-    let square_id = std::env::var("SQUARE").expect("Missing SQUARE env var");
-    let square_id = u64::from_str(&square_id).expect("Invalid SQUARE");
-    
-    // Check balance before deploying
-    let balance = rpc.get_balance(&payer.pubkey()).await?;
-    let min_balance_needed = amount + 10_000 + 2_000_000 + 890_880; // deploy + checkpoint + fees + rent-exempt
-    
-    if balance < min_balance_needed {
-        eprintln!("❌ Insufficient balance");
-        eprintln!("   Current balance: {} SOL ({} lamports)", balance as f64 / 1_000_000_000.0, balance);
-        eprintln!("   Minimum needed: {} SOL ({} lamports)", min_balance_needed as f64 / 1_000_000_000.0, min_balance_needed);
-        eprintln!("   Deposit at least {} SOL to address: {}", (min_balance_needed - balance) as f64 / 1_000_000_000.0, payer.pubkey());
-        return Err(anyhow::anyhow!("Insufficient balance"));
+    let max_wrap = std::env::var("MAX_WRAP").expect("Missing MAX_WRAP env var");
+    let max_wrap = u64::from_str(&max_wrap).expect("Invalid MAX_WRAP");
+    let treasury_reserve =
+        std::env::var("TREASURY_RESERVE").expect("Missing TREASURY_RESERVE env var");
+    let treasury_reserve = u64::from_str(&treasury_reserve).expect("Invalid TREASURY_RESERVE");
+    let ix = ore_api::sdk::set_wrap_policy(payer.pubkey(), max_wrap, treasury_reserve);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn import_allowlist(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    // Read one pubkey per line, skipping blank lines and comments.
+    let file = std::env::var("FILE").expect("Missing FILE env var");
+    let contents = std::fs::read_to_string(&file)?;
+    let authorities = contents
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| Pubkey::from_str(line).map_err(|_| anyhow::anyhow!("Invalid pubkey: {}", line)))
+        .collect::<Result<Vec<Pubkey>, anyhow::Error>>()?;
+
+    // Skip authorities already on the allowlist.
+    let mut ixs = vec![];
+    for authority in authorities {
+        let allowlist_address = allowlist_pda(authority).0;
+        if rpc.get_account(&allowlist_address).await.is_ok() {
+            continue;
+        }
+        ixs.push(ore_api::sdk::add_to_allowlist(payer.pubkey(), authority));
     }
-    
-    let board = get_board(rpc).await?;
-    let mut squares = [false; 25];
-    squares[square_id as usize] = true;
-    let ix = ore_api::sdk::deploy(
-        payer.pubkey(),
-        payer.pubkey(),
-        amount,
-        board.round_id,
-        squares,
-    );
+    println!("Adding {} authorities to the allowlist", ixs.len());
+
+    // Batch and submit the instructions.
+    while !ixs.is_empty() {
+        let batch = ixs
+            .drain(..std::cmp::min(10, ixs.len()))
+            .collect::<Vec<Instruction>>();
+        submit_transaction(rpc, payer, &batch).await?;
+    }
+
+    Ok(())
+}
+
+async fn remove_from_allowlist(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").expect("Missing AUTHORITY env var");
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let ix = ore_api::sdk::remove_from_allowlist(payer.pubkey(), authority);
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn deploy_all(
+async fn set_allowlist_enabled(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
-    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
-    let board = get_board(rpc).await?;
-    let squares = [true; 25];
-    let ix = ore_api::sdk::deploy(
-        payer.pubkey(),
+    let enabled = std::env::var("ENABLED").expect("Missing ENABLED env var");
+    let enabled = bool::from_str(&enabled).expect("Invalid ENABLED");
+    let ix = ore_api::sdk::set_allowlist_enabled(payer.pubkey(), enabled);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_collection(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mint_authority = std::env::var("MINT_AUTHORITY").expect("Missing MINT_AUTHORITY env var");
+    let mint_authority = Pubkey::from_str(&mint_authority).expect("Invalid MINT_AUTHORITY");
+    let metadata_address =
+        std::env::var("METADATA_ADDRESS").unwrap_or(Pubkey::default().to_string());
+    let metadata_address = Pubkey::from_str(&metadata_address).expect("Invalid METADATA_ADDRESS");
+    let token_program = std::env::var("TOKEN_PROGRAM").expect("Missing TOKEN_PROGRAM env var");
+    let token_program = Pubkey::from_str(&token_program).expect("Invalid TOKEN_PROGRAM");
+    let perk = std::env::var("PERK").expect("Missing PERK env var");
+    let perk = match perk.as_str() {
+        "stake_multiplier" => CollectionPerk::StakeMultiplier,
+        "claim_fee_discount" => CollectionPerk::ClaimFeeDiscount,
+        _ => panic!("Invalid PERK"),
+    };
+    let perk_bps = std::env::var("PERK_BPS").expect("Missing PERK_BPS env var");
+    let perk_bps = u64::from_str(&perk_bps).expect("Invalid PERK_BPS");
+    let ix = ore_api::sdk::set_collection(
         payer.pubkey(),
-        board.round_id,
-        amount,
-        squares,
+        mint_authority,
+        metadata_address,
+        token_program,
+        perk,
+        perk_bps,
     );
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn claim_seeker(
+async fn remove_collection(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let seeker_mint = pubkey!("5mXbkqKz883aufhAsx3p5Z1NcvD2ppZbdTTznM6oUKLj");
-    let ix = ore_api::sdk::claim_seeker(payer.pubkey(), seeker_mint);
-    // submit_transaction(rpc, payer, &[ix]).await?;
-    simulate_transaction(rpc, payer, &[ix]).await;
+    let mint_authority = std::env::var("MINT_AUTHORITY").expect("Missing MINT_AUTHORITY env var");
+    let mint_authority = Pubkey::from_str(&mint_authority).expect("Invalid MINT_AUTHORITY");
+    let ix = ore_api::sdk::remove_collection(payer.pubkey(), mint_authority);
+    submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn set_admin(
+async fn set_seeker_activation(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let ix = ore_api::sdk::set_admin(payer.pubkey(), payer.pubkey());
+    let enabled = std::env::var("ENABLED").expect("Missing ENABLED env var");
+    let enabled = bool::from_str(&enabled).expect("Invalid ENABLED");
+    let ix = ore_api::sdk::set_seeker_activation(payer.pubkey(), enabled);
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
@@ -337,6 +1502,53 @@ async fn set_fee_collector(
     Ok(())
 }
 
+async fn set_fee_split(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    // Recipients are given as a comma-separated list of ADDRESS:BPS pairs.
+    let fee_split = std::env::var("FEE_SPLIT").expect("Missing FEE_SPLIT env var");
+    let recipients = fee_split
+        .split(',')
+        .map(|recipient| {
+            let (address, bps) = recipient.split_once(':').expect("Invalid FEE_SPLIT");
+            let address = Pubkey::from_str(address.trim()).expect("Invalid FEE_SPLIT address");
+            let bps = u64::from_str(bps.trim()).expect("Invalid FEE_SPLIT bps");
+            (address, bps)
+        })
+        .collect::<Vec<_>>();
+    if recipients.len() > MAX_FEE_RECIPIENTS {
+        return Err(anyhow::anyhow!(
+            "Fee split exceeds maximum of {} recipients",
+            MAX_FEE_RECIPIENTS
+        ));
+    }
+    if recipients.iter().map(|(_, bps)| bps).sum::<u64>() != DENOMINATOR_BPS {
+        return Err(anyhow::anyhow!(
+            "Fee split weights must sum to {} bps",
+            DENOMINATOR_BPS
+        ));
+    }
+    let ix = ore_api::sdk::set_fee_split(payer.pubkey(), &recipients);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn distribute_fees(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let fee_split = get_fee_split(rpc).await?;
+    let recipients = fee_split
+        .recipients()
+        .iter()
+        .map(|recipient| recipient.address)
+        .collect::<Vec<_>>();
+    let ix = ore_api::sdk::distribute_fees(payer.pubkey(), &recipients);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
 async fn checkpoint(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
@@ -344,7 +1556,14 @@ async fn checkpoint(
     let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
     let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
     let miner = get_miner(rpc, authority).await?;
-    let ix = ore_api::sdk::checkpoint(payer.pubkey(), authority, miner.round_id);
+    let season_id = get_round_season_id(rpc, miner.round_id).await;
+    let ix = ore_api::sdk::checkpoint(
+        payer.pubkey(),
+        authority,
+        miner.round_id,
+        season_id,
+        miner.referrer,
+    );
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
@@ -359,15 +1578,15 @@ async fn checkpoint_all(
     let mut ixs = vec![];
     for (i, (_address, miner)) in miners.iter().enumerate() {
         if miner.checkpoint_id < miner.round_id {
-            // Log the expiry slot for the round.
+            // Log the expiry slot and season for the round.
             if !expiry_slots.contains_key(&miner.round_id) {
                 if let Ok(round) = get_round(rpc, miner.round_id).await {
-                    expiry_slots.insert(miner.round_id, round.expires_at);
+                    expiry_slots.insert(miner.round_id, (round.expires_at, round.season_id));
                 }
             }
 
             // Get the expiry slot for the round.
-            let Some(expires_at) = expiry_slots.get(&miner.round_id) else {
+            let Some((expires_at, season_id)) = expiry_slots.get(&miner.round_id) else {
                 continue;
             };
 
@@ -384,6 +1603,8 @@ async fn checkpoint_all(
                     payer.pubkey(),
                     miner.authority,
                     miner.round_id,
+                    *season_id,
+                    miner.referrer,
                 ));
             }
         }
@@ -405,6 +1626,7 @@ async fn close_all(
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let rounds = get_rounds(rpc).await?;
+    let config = get_config(rpc).await?;
     let mut ixs = vec![];
     let clock = get_clock(rpc).await?;
     for (_i, (_address, round)) in rounds.iter().enumerate() {
@@ -416,6 +1638,11 @@ async fn close_all(
             ));
         }
     }
+    println!(
+        "Closing {} rounds (bounty: up to {} SOL each)",
+        ixs.len(),
+        config.close_bounty as f64 / LAMPORTS_PER_SOL as f64
+    );
 
     // Batch and submit the instructions.
     while !ixs.is_empty() {
@@ -484,6 +1711,21 @@ async fn log_automations(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+async fn log_collections(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let collections = get_program_accounts::<Collection>(rpc, ore_api::ID, vec![]).await?;
+    for (i, (address, collection)) in collections.iter().enumerate() {
+        println!("[{}/{}] {}", i + 1, collections.len(), address);
+        println!("  mint_authority: {}", collection.mint_authority);
+        println!("  metadata_address: {}", collection.metadata_address);
+        println!("  token_program: {}", collection.token_program);
+        println!("  perk: {:?}", collection.perk());
+        println!("  perk_bps: {}", collection.perk_bps);
+        println!("  total_claimed: {}", collection.total_claimed);
+        println!();
+    }
+    Ok(())
+}
+
 async fn log_treasury(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     let treasury_address = ore_api::state::treasury_pda().0;
     let treasury = get_treasury(rpc).await?;
@@ -503,7 +1745,7 @@ async fn log_treasury(rpc: &RpcClient) -> Result<(), anyhow::Error> {
         treasury.stake_rewards_factor.to_i80f48().to_string()
     );
     println!(
-        "  total_staked: {} ORE",
+        "  total_staked (weighted): {} ORE",
         amount_to_ui_amount(treasury.total_staked, TOKEN_DECIMALS)
     );
     println!(
@@ -514,6 +1756,14 @@ async fn log_treasury(rpc: &RpcClient) -> Result<(), anyhow::Error> {
         "  total_refined: {} ORE",
         amount_to_ui_amount(treasury.total_refined, TOKEN_DECIMALS)
     );
+    println!(
+        "  motherlode_sol: {} SOL",
+        treasury.motherlode_sol as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  stake_sol_rewards_factor: {}",
+        treasury.stake_sol_rewards_factor.to_i80f48().to_string()
+    );
     Ok(())
 }
 
@@ -525,27 +1775,132 @@ async fn log_round(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     let rng = round.rng();
     println!("Round");
     println!("  Address: {}", round_address);
+    println!("  Bonus SOL: {}", round.bonus_sol);
+    println!("  Bonus ORE: {}", round.bonus_ore);
     println!("  Count: {:?}", round.count);
     println!("  Deployed: {:?}", round.deployed);
     println!("  Expires at: {}", round.expires_at);
     println!("  Id: {:?}", round.id);
     println!("  Motherlode: {}", round.motherlode);
+    println!("  Motherlode SOL: {}", round.motherlode_sol);
+    println!("  Referral fee: {}", round.referral_fee);
     println!("  Rent payer: {}", round.rent_payer);
     println!("  Slot hash: {:?}", round.slot_hash);
+    println!("  Sample slot: {}", round.sample_slot);
+    println!("  Season: {}", round.season_id);
+    println!("  Entropy source: {:?}", round.entropy_source());
+    println!("  Entropy commit: {:?}", round.entropy_commit);
+    println!("  Entropy reveal: {:?}", round.entropy_reveal);
     println!("  Top miner: {:?}", round.top_miner);
+    println!("  Total referred: {}", round.total_referred);
     println!("  Top miner reward: {}", round.top_miner_reward);
     println!("  Total deployed: {}", round.total_deployed);
     println!("  Total vaulted: {}", round.total_vaulted);
     println!("  Total winnings: {}", round.total_winnings);
+    println!("  Squares: {}", round.num_squares());
+    println!("  Winning square count: {}", round.num_winning_squares());
     if let Some(rng) = rng {
         println!("  Winning square: {}", round.winning_square(rng));
+        println!(
+            "  Winning squares: {:?}",
+            winning_squares(round.winning_mask(rng))
+        );
     }
     // if round.slot_hash != [0; 32] {
-    //     println!("  Winning square: {}", get_winning_square(&round.slot_hash));
+    //     println!(
+    //         "  Winning square: {}",
+    //         get_winning_square(&round.slot_hash, round.num_squares() as u64)
+    //     );
     // }
     Ok(())
 }
 
+async fn log_history(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let count = std::env::var("COUNT").unwrap_or(HISTORY_SIZE.to_string());
+    let count = usize::from_str(&count).expect("Invalid COUNT");
+    let history = get_history(rpc).await?;
+    println!("History ({} rounds recorded)", history.total);
+    for summary in history.latest().take(count) {
+        let winning_squares = if summary.winning_mask == 0 {
+            "refunded".to_string()
+        } else {
+            format!("{:?}", winning_squares(summary.winning_mask))
+        };
+        let top_miner = if summary.is_split > 0 {
+            "split".to_string()
+        } else {
+            summary.top_miner.to_string()
+        };
+        println!(
+            "  Round {}: squares {}, deployed {} SOL, winnings {} SOL, motherlode {} ORE, top miner {}",
+            summary.round_id,
+            winning_squares,
+            summary.total_deployed as f64 / LAMPORTS_PER_SOL as f64,
+            summary.total_winnings as f64 / LAMPORTS_PER_SOL as f64,
+            amount_to_ui_amount(summary.motherlode, TOKEN_DECIMALS),
+            top_miner
+        );
+    }
+    Ok(())
+}
+
+async fn log_fee_split(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let fee_split_address = fee_split_pda().0;
+    let fee_split = get_fee_split(rpc).await?;
+    println!("Fee split");
+    println!("  address: {}", fee_split_address);
+    println!("  active: {}", config.fee_collector == fee_split_address);
+    println!(
+        "  balance: {} SOL",
+        fee_split.balance as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  total_distributed: {} SOL",
+        fee_split.total_distributed as f64 / LAMPORTS_PER_SOL as f64
+    );
+    for recipient in fee_split.recipients() {
+        println!("  {}: {} bps", recipient.address, recipient.bps);
+    }
+    Ok(())
+}
+
+async fn log_season(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let id = std::env::var("ID").unwrap_or(config.season_id.to_string());
+    let id = u64::from_str(&id).expect("Invalid ID");
+    let season_address = season_pda(id).0;
+    let season = get_season(rpc, id).await?;
+    println!("Season");
+    println!("  address: {}", season_address);
+    println!("  id: {}", season.id);
+    println!("  start_round: {}", season.start_round);
+    println!("  end_round: {}", season.end_round);
+    println!(
+        "  reward_sol: {} SOL",
+        season.reward_sol as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  reward_ore: {} ORE",
+        amount_to_ui_amount(season.reward_ore, TOKEN_DECIMALS)
+    );
+    println!("  claimable_at: {}", season.claimable_at);
+    println!("  total_miners: {}", season.total_miners);
+    println!("  leaderboard:");
+    for (rank, entry) in season.leaderboard.iter().enumerate() {
+        if entry.score == 0 {
+            break;
+        }
+        println!(
+            "    {}. {} ({} ORE)",
+            rank + 1,
+            entry.authority,
+            amount_to_ui_amount(entry.score, TOKEN_DECIMALS)
+        );
+    }
+    Ok(())
+}
+
 async fn log_miner(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
@@ -578,6 +1933,9 @@ async fn log_miner(
         "  lifetime_rewards_ore: {} ORE",
         amount_to_ui_amount(miner.lifetime_rewards_ore, TOKEN_DECIMALS)
     );
+    println!("  claim_delegate: {}", miner.claim_delegate);
+    println!("  payout_recipient: {}", miner.payout_recipient());
+    println!("  referrer: {}", miner.referrer);
     Ok(())
 }
 
@@ -614,6 +1972,49 @@ async fn log_config(rpc: &RpcClient) -> Result<(), anyhow::Error> {
         "  is_seeker_activation_enabled: {}",
         config.is_seeker_activation_enabled
     );
+    println!("  randomness_authority: {}", config.randomness_authority);
+    println!("  entropy_source: {:?}", config.entropy_source());
+    println!(
+        "  reset_bounty: {} SOL",
+        config.reset_bounty as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  close_bounty: {} SOL",
+        config.close_bounty as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!("  close_destination: {:?}", config.close_destination());
+    println!("  stake_admin_fee_bps: {}", config.stake_admin_fee_bps);
+    println!("  stake_vault_bps: {}", config.stake_vault_bps);
+    println!("  seeker_multiplier_bps: {}", config.seeker_multiplier_bps);
+    println!("  unstake_cooldown: {} s", config.unstake_cooldown);
+    println!(
+        "  stake_claim_fee_discount_bps: {}",
+        config.stake_claim_fee_discount_bps
+    );
+    println!("  referral_fee_bps: {}", config.referral_fee_bps);
+    println!(
+        "  min_deploy_amount: {} SOL",
+        config.min_deploy_amount as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  max_deploy_per_round: {} SOL",
+        config.max_deploy_per_round as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  max_deploy_per_square: {} SOL",
+        config.max_deploy_per_square as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!("  allowlist_enabled: {}", config.allowlist_enabled);
+    println!("  season_id: {}", config.season_id);
+    println!("  winning_squares: {}", config.winning_squares);
+    println!(
+        "  max_wrap: {} SOL",
+        config.max_wrap as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  treasury_reserve: {} SOL",
+        config.treasury_reserve as f64 / LAMPORTS_PER_SOL as f64
+    );
 
     Ok(())
 }
@@ -640,7 +2041,7 @@ async fn get_available_blocks_json(rpc: &RpcClient) -> Result<(), anyhow::Error>
     
     // Find available blocks (where deployed amount is below threshold)
     let mut available_blocks = Vec::new();
-    for (i, &deployed) in round.deployed.iter().enumerate() {
+    for (i, &deployed) in round.deployed.iter().take(round.num_squares()).enumerate() {
         if deployed < threshold_lamports {
             available_blocks.push(i);
         }
@@ -658,6 +2059,7 @@ fn print_board(board: Board, clock: &Clock) {
     println!("  Id: {:?}", board.round_id);
     println!("  Start slot: {}", board.start_slot);
     println!("  End slot: {}", board.end_slot);
+    println!("  Squares: {}", board.num_squares());
     println!(
         "  Time remaining: {} sec",
         (board.end_slot.saturating_sub(current_slot) as f64) * 0.4
@@ -708,6 +2110,42 @@ async fn get_round(rpc: &RpcClient, id: u64) -> Result<Round, anyhow::Error> {
     Ok(*round)
 }
 
+async fn get_bonus(rpc: &RpcClient, round_id: u64) -> Result<Bonus, anyhow::Error> {
+    let bonus_pda = ore_api::state::bonus_pda(round_id);
+    let account = rpc.get_account(&bonus_pda.0).await?;
+    let bonus = Bonus::try_from_bytes(&account.data)?;
+    Ok(*bonus)
+}
+
+async fn get_history(rpc: &RpcClient) -> Result<History, anyhow::Error> {
+    let history_pda = ore_api::state::history_pda();
+    let account = rpc.get_account(&history_pda.0).await?;
+    let history = History::try_from_bytes(&account.data)?;
+    Ok(*history)
+}
+
+async fn get_fee_split(rpc: &RpcClient) -> Result<FeeSplit, anyhow::Error> {
+    let fee_split_pda = ore_api::state::fee_split_pda();
+    let account = rpc.get_account(&fee_split_pda.0).await?;
+    let fee_split = FeeSplit::try_from_bytes(&account.data)?;
+    Ok(*fee_split)
+}
+
+async fn get_season(rpc: &RpcClient, id: u64) -> Result<Season, anyhow::Error> {
+    let season_pda = ore_api::state::season_pda(id);
+    let account = rpc.get_account(&season_pda.0).await?;
+    let season = Season::try_from_bytes(&account.data)?;
+    Ok(*season)
+}
+
+/// Returns the season of a round, or 0 if the round has been closed.
+async fn get_round_season_id(rpc: &RpcClient, id: u64) -> u64 {
+    get_round(rpc, id)
+        .await
+        .map(|round| round.season_id)
+        .unwrap_or(0)
+}
+
 async fn get_treasury(rpc: &RpcClient) -> Result<Treasury, anyhow::Error> {
     let treasury_pda = ore_api::state::treasury_pda();
     let account = rpc.get_account(&treasury_pda.0).await?;
@@ -749,12 +2187,27 @@ async fn get_stake(rpc: &RpcClient, authority: Pubkey) -> Result<Stake, anyhow::
     Ok(*stake)
 }
 
+async fn get_mining_pool(
+    rpc: &RpcClient,
+    operator: Pubkey,
+) -> Result<ore_api::state::Pool, anyhow::Error> {
+    let pool_address = pool_pda(operator).0;
+    let account = rpc.get_account(&pool_address).await?;
+    let pool = ore_api::state::Pool::try_from_bytes(&account.data)?;
+    Ok(*pool)
+}
+
 async fn get_rounds(rpc: &RpcClient) -> Result<Vec<(Pubkey, Round)>, anyhow::Error> {
     let rounds = get_program_accounts::<Round>(rpc, ore_api::ID, vec![]).await?;
     Ok(rounds)
 }
 
 #[allow(dead_code)]
+async fn get_stakes(rpc: &RpcClient) -> Result<Vec<(Pubkey, Stake)>, anyhow::Error> {
+    let stakes = get_program_accounts::<Stake>(rpc, ore_api::ID, vec![]).await?;
+    Ok(stakes)
+}
+
 async fn get_miners(rpc: &RpcClient) -> Result<Vec<(Pubkey, Miner)>, anyhow::Error> {
     let miners = get_program_accounts::<Miner>(rpc, ore_api::ID, vec![]).await?;
     Ok(miners)
@@ -769,7 +2222,7 @@ async fn get_miners_participating(
     Ok(miners)
 }
 
-fn get_winning_square(slot_hash: &[u8]) -> u64 {
+fn get_winning_square(slot_hash: &[u8], num_squares: u64) -> u64 {
     // Use slot hash to generate a random u64
     let r1 = u64::from_le_bytes(slot_hash[0..8].try_into().unwrap());
     let r2 = u64::from_le_bytes(slot_hash[8..16].try_into().unwrap());
@@ -777,8 +2230,13 @@ fn get_winning_square(slot_hash: &[u8]) -> u64 {
     let r4 = u64::from_le_bytes(slot_hash[24..32].try_into().unwrap());
     let r = r1 ^ r2 ^ r3 ^ r4;
 
-    // Returns a value in the range [0, 24] inclusive
-    r % 25
+    // Returns a value in the range [0, num_squares) exclusive
+    r % num_squares
+}
+
+/// Returns the squares set in a winning mask.
+fn winning_squares(mask: u64) -> Vec<usize> {
+    (0..64).filter(|&i| is_winning_square(mask, i)).collect()
 }
 
 #[allow(dead_code)]
@@ -893,6 +2351,34 @@ async fn submit_transaction_no_confirm(
     }
 }
 
+async fn get_outdated_accounts(
+    rpc: &RpcClient,
+    discriminator: u8,
+    size: usize,
+) -> Result<Vec<Pubkey>, anyhow::Error> {
+    let accounts = rpc
+        .get_program_accounts_with_config(
+            &ore_api::ID,
+            RpcProgramAccountsConfig {
+                filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                    0,
+                    &[discriminator],
+                ))]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await?;
+    Ok(accounts
+        .into_iter()
+        .filter(|(_, account)| account.data.len() < size)
+        .map(|(address, _)| address)
+        .collect())
+}
+
 pub async fn get_program_accounts<T>(
     client: &RpcClient,
     program_id: Pubkey,
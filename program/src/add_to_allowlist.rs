@@ -0,0 +1,43 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Adds an authority to the allowlist.
+pub fn process_add_to_allowlist(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = AddToAllowlist::try_from_bytes(data)?;
+    let authority = Pubkey::new_from_array(args.authority);
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, allowlist_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    allowlist_info
+        .is_writable()?
+        .has_seeds(&[ALLOWLIST, &authority.to_bytes()], &ore_api::ID)?;
+    config_info.as_account::<Config>(&ore_api::ID)?.assert_err(
+        |c| c.admin == *signer_info.key,
+        OreError::NotAuthorized.into(),
+    )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Skip authorities already on the allowlist.
+    if !allowlist_info.data_is_empty() {
+        return Ok(());
+    }
+
+    // Create allowlist entry.
+    create_program_account::<Allowlist>(
+        allowlist_info,
+        system_program,
+        signer_info,
+        &ore_api::ID,
+        &[ALLOWLIST, &authority.to_bytes()],
+    )?;
+    let allowlist = allowlist_info.as_account_mut::<Allowlist>(&ore_api::ID)?;
+    allowlist.authority = authority;
+    allowlist.created_at = clock.unix_timestamp;
+
+    Ok(())
+}
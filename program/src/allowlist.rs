@@ -0,0 +1,21 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Checks the authority has an allowlist entry, if the allowlist is enabled.
+pub fn check_allowlist(
+    config: &Config,
+    allowlist_info: &AccountInfo<'_>,
+    authority: &Pubkey,
+) -> ProgramResult {
+    if config.allowlist_enabled == 0 {
+        return Ok(());
+    }
+    allowlist_info.has_seeds(&[ALLOWLIST, &authority.to_bytes()], &ore_api::ID)?;
+    if allowlist_info
+        .as_account::<Allowlist>(&ore_api::ID)
+        .is_err()
+    {
+        return Err(OreError::NotAllowlisted.into());
+    }
+    Ok(())
+}
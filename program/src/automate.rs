@@ -1,6 +1,8 @@
 use ore_api::prelude::*;
 use steel::*;
 
+use crate::allowlist::check_allowlist;
+
 /// Sets the executor.
 pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
@@ -12,18 +14,16 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
     let strategy = AutomationStrategy::from_u64(args.strategy as u64);
 
     // Load accounts.
-    let [signer_info, automation_info, executor_info, miner_info, system_program] = accounts else {
+    let [signer_info, allowlist_info, automation_info, config_info, executor_info, miner_info, system_program] =
+        accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
     automation_info.is_writable()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
     system_program.is_program(&system_program::ID)?;
 
-    // // Check whitelist
-    // if !AUTHORIZED_ACCOUNTS.contains(&signer_info.key) {
-    //     return Err(trace("Not authorized", OreError::NotAuthorized.into()));
-    // }
-
     // Open miner account.
     let miner = if miner_info.data_is_empty() {
         create_program_account::<Miner>(
@@ -35,8 +35,8 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
         )?;
         let miner = miner_info.as_account_mut::<Miner>(&ore_api::ID)?;
         miner.authority = *signer_info.key;
-        miner.deployed = [0; 25];
-        miner.cumulative = [0; 25];
+        miner.deployed = [0; MAX_SQUARES];
+        miner.cumulative = [0; MAX_SQUARES];
         miner.checkpoint_fee = 0;
         miner.checkpoint_id = 0;
         miner.rewards_sol = 0;
@@ -66,6 +66,9 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
         return Ok(());
     }
 
+    // Check allowlist.
+    check_allowlist(config, allowlist_info, signer_info.key)?;
+
     // Create automation.
     let automation = if automation_info.data_is_empty() {
         create_program_account::<Automation>(
@@ -5,11 +5,14 @@ use solana_program::native_token::lamports_to_sol;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::wrap::wrap_sol;
+
 /// Swap vaulted SOL to ORE, and burn the ORE.
 pub fn process_bury(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = Bury::try_from_bytes(data)?;
     let min_amount_out = u64::from_le_bytes(args.min_amount_out);
+    let wrap_amount = u64::from_le_bytes(args.wrap_amount);
 
     // Load accounts.
     let (ore_accounts, meteora_accounts) = accounts.split_at(11);
@@ -20,7 +23,7 @@ pub fn process_bury(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult
     };
     signer_info.is_signer()?;
     board_info.as_account_mut::<Board>(&ore_api::ID)?;
-    config_info
+    let config = config_info
         .as_account::<Config>(&ore_api::ID)?
         .assert(|c| c.bury_authority == *signer_info.key)?;
     mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
@@ -40,6 +43,19 @@ pub fn process_bury(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    // Wrap SOL from the treasury, so a separate wrap is not needed.
+    if wrap_amount > 0 {
+        wrap_sol(
+            config,
+            treasury,
+            board_info,
+            treasury_info,
+            treasury_sol_info,
+            ore_program,
+            wrap_amount,
+        )?;
+    }
+
     // Sync native token balance.
     sync_native(treasury_sol_info)?;
 
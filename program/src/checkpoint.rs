@@ -3,19 +3,33 @@ use solana_program::{log::sol_log, native_token::lamports_to_sol, rent::Rent};
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::migrate_account;
+
 /// Checkpoints a miner's rewards.
 pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, board_info, miner_info, round_info, treasury_info, system_program] = accounts
+    let [signer_info, board_info, history_info, miner_info, round_info, treasury_info, referrer_info, season_info, season_miner_info, system_program, ore_program] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
+
+    // Migrate accounts created before the board size was configurable.
+    migrate_account::<Miner>(miner_info, signer_info)?;
+    if !round_info.data_is_empty() {
+        migrate_account::<Round>(round_info, signer_info)?;
+    }
+
     let board = board_info.as_account::<Board>(&ore_api::ID)?;
+    history_info
+        .is_writable()?
+        .has_seeds(&[HISTORY], &ore_api::ID)?;
     let miner = miner_info.as_account_mut::<Miner>(&ore_api::ID)?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
     system_program.is_program(&system_program::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
 
     // If miner has already checkpointed this round, return.
     if miner.checkpoint_id == miner.round_id {
@@ -62,31 +76,32 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
 
     // Get the RNG.
     if let Some(r) = round.rng() {
-        // Get the winning square.
-        let winning_square = round.winning_square(r) as usize;
+        // Get the winning squares.
+        let winning_mask = round.winning_mask(r);
+        let winning_deployed = miner.winning_deployed(winning_mask);
+        let round_winning_deployed = round.winning_deployed(winning_mask);
 
-        // If the miner deployed to the winning square, calculate rewards.
-        if miner.deployed[winning_square] > 0 {
+        // If the miner deployed to a winning square, calculate rewards.
+        if winning_deployed > 0 {
             // Sanity check.
             assert!(
-                round.deployed[winning_square] >= miner.deployed[winning_square],
+                round_winning_deployed >= winning_deployed,
                 "Invalid round deployed amount"
             );
 
             // Calculate SOL rewards.
-            let original_deployment = miner.deployed[winning_square];
+            let original_deployment = winning_deployed;
             let admin_fee = (original_deployment / 100).max(1);
             rewards_sol = original_deployment - admin_fee;
-            rewards_sol += ((round.total_winnings as u128 * miner.deployed[winning_square] as u128)
-                / round.deployed[winning_square] as u128) as u64;
+            rewards_sol += ((round.total_winnings as u128 * winning_deployed as u128)
+                / round_winning_deployed as u128) as u64;
             sol_log(&format!("Base rewards: {} SOL", lamports_to_sol(rewards_sol)).as_str());
 
             // Calculate ORE rewards.
             if round.top_miner == SPLIT_ADDRESS {
                 // If round is split, split the reward evenly among all miners.
-                rewards_ore = ((round.top_miner_reward as u128
-                    * miner.deployed[winning_square] as u128)
-                    / round.deployed[winning_square] as u128) as u64;
+                rewards_ore = ((round.top_miner_reward as u128 * winning_deployed as u128)
+                    / round_winning_deployed as u128) as u64;
                 sol_log(
                     &format!(
                         "Split rewards: {} ORE",
@@ -96,13 +111,26 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
                 );
             } else {
                 // If round is not split, payout to the top miner.
-                let top_miner_sample = round.top_miner_sample(r, winning_square);
-                if top_miner_sample >= miner.cumulative[winning_square]
-                    && top_miner_sample
-                        < miner.cumulative[winning_square] + miner.deployed[winning_square]
-                {
+                // The winning squares are laid end to end, so one miner is sampled across all of them.
+                let top_miner_sample = round.top_miner_sample(r, winning_mask);
+                let is_top_miner = (0..miner.deployed.len())
+                    .filter(|&i| is_winning_square(winning_mask, i))
+                    .any(|i| {
+                        let start = round.winning_offset(winning_mask, i) + miner.cumulative[i];
+                        top_miner_sample >= start && top_miner_sample < start + miner.deployed[i]
+                    });
+                if is_top_miner {
                     rewards_ore = round.top_miner_reward;
                     round.top_miner = miner.authority;
+
+                    // Record the top miner in the round history.
+                    if !history_info.data_is_empty() {
+                        let history = history_info.as_account_mut::<History>(&ore_api::ID)?;
+                        if let Some(summary) = history.get_mut(round.id) {
+                            summary.top_miner = miner.authority;
+                        }
+                    }
+
                     sol_log(
                         &format!(
                             "Top miner rewards: {} ORE",
@@ -115,9 +143,9 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
 
             // Calculate motherlode rewards.
             if round.motherlode > 0 {
-                let motherload_rewards =
-                    ((round.motherlode as u128 * miner.deployed[winning_square] as u128)
-                        / round.deployed[winning_square] as u128) as u64;
+                let motherload_rewards = ((round.motherlode as u128 * winning_deployed as u128)
+                    / round_winning_deployed as u128)
+                    as u64;
                 sol_log(
                     &format!(
                         "Motherlode rewards: {} ORE",
@@ -127,6 +155,47 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
                 );
                 rewards_ore += motherload_rewards;
             }
+
+            // Calculate SOL motherlode rewards.
+            if round.motherlode_sol > 0 {
+                let motherlode_sol_rewards =
+                    ((round.motherlode_sol as u128 * winning_deployed as u128)
+                        / round_winning_deployed as u128) as u64;
+                sol_log(
+                    &format!(
+                        "Motherlode rewards: {} SOL",
+                        lamports_to_sol(motherlode_sol_rewards)
+                    )
+                    .as_str(),
+                );
+                rewards_sol += motherlode_sol_rewards;
+            }
+
+            // Calculate sponsored bonus rewards.
+            if round.bonus_sol > 0 {
+                let bonus_sol_rewards = ((round.bonus_sol as u128 * winning_deployed as u128)
+                    / round_winning_deployed as u128)
+                    as u64;
+                sol_log(
+                    &format!("Bonus rewards: {} SOL", lamports_to_sol(bonus_sol_rewards)).as_str(),
+                );
+                rewards_sol += bonus_sol_rewards;
+            }
+            if round.bonus_ore > 0 {
+                let bonus_ore_rewards = ((round.bonus_ore as u128 * winning_deployed as u128)
+                    / round_winning_deployed as u128)
+                    as u64;
+                sol_log(
+                    &format!(
+                        "Bonus rewards: {} ORE",
+                        amount_to_ui_amount(bonus_ore_rewards, TOKEN_DECIMALS)
+                    )
+                    .as_str(),
+                );
+                rewards_ore += bonus_ore_rewards;
+                round.unclaimed_bonus_ore =
+                    round.unclaimed_bonus_ore.saturating_sub(bonus_ore_rewards);
+            }
         }
     } else {
         // Sanity check.
@@ -155,6 +224,63 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     // Update treasury.
     treasury.total_unclaimed += rewards_ore;
 
+    // Update the miner's season stats and the season leaderboard.
+    // The season miner account is opened at deploy, so the checkpoint signer never pays its rent.
+    if round.season_id > 0 && round.rng().is_some() {
+        let season_id = round.season_id.to_le_bytes();
+        let season = season_info
+            .is_writable()?
+            .has_seeds(&[SEASON, &season_id], &ore_api::ID)?
+            .as_account_mut::<Season>(&ore_api::ID)?;
+        season_miner_info.is_writable()?.has_seeds(
+            &[SEASON_MINER, &season_id, &miner.authority.to_bytes()],
+            &ore_api::ID,
+        )?;
+        // Skip miners whose season account was not opened at deploy.
+        if !season_miner_info.data_is_empty() {
+            let season_miner = season_miner_info.as_account_mut::<SeasonMiner>(&ore_api::ID)?;
+            if season_miner.rounds_played == 0 {
+                season.total_miners += 1;
+            }
+            season_miner.sol_deployed += miner.deployed.iter().sum::<u64>();
+            season_miner.sol_won += rewards_sol;
+            season_miner.ore_won += rewards_ore;
+            season_miner.rounds_played += 1;
+            season.update_leaderboard(miner.authority, season_miner.ore_won);
+        }
+    }
+
+    // Credit the referrer with a share of the referral fee.
+    // The referrer account is opened at deploy, when the miner assigns its referrer.
+    let referral_amount = if miner.referrer != Pubkey::default() {
+        round.referral_share(miner.deployed.iter().sum::<u64>())
+    } else {
+        0
+    };
+    if referral_amount > 0 {
+        referrer_info
+            .is_writable()?
+            .has_seeds(&[REFERRER, &miner.referrer.to_bytes()], &ore_api::ID)?;
+    }
+    if referral_amount > 0 && !referrer_info.data_is_empty() {
+        let referrer = referrer_info.as_account_mut::<Referrer>(&ore_api::ID)?;
+        referrer.rewards_sol += referral_amount;
+        referrer.lifetime_rewards_sol += referral_amount;
+        round_info.send(referral_amount, &referrer_info);
+        program_log(
+            &[board_info.clone(), ore_program.clone()],
+            ReferralEvent {
+                disc: 4,
+                round_id: round.id,
+                referrer: miner.referrer,
+                miner: miner.authority,
+                amount: referral_amount,
+                ts: clock.unix_timestamp,
+            }
+            .to_bytes(),
+        )?;
+    }
+
     // Do SOL transfers.
     if rewards_sol > 0 {
         round_info.send(rewards_sol, &miner_info);
@@ -7,7 +7,7 @@ use steel::*;
 pub fn process_claim_ore(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, miner_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+    let [signer_info, miner_info, mint_info, owner_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -15,8 +15,9 @@ pub fn process_claim_ore(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
     signer_info.is_signer()?;
     let miner = miner_info
         .as_account_mut::<Miner>(&ore_api::ID)?
-        .assert_mut(|m| m.authority == *signer_info.key)?;
+        .assert_mut(|m| m.can_claim(signer_info.key))?;
     mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    owner_info.has_address(&miner.payout_recipient())?;
     recipient_info.is_writable()?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
     treasury_tokens_info.as_associated_token_account(&treasury_info.key, &mint_info.key)?;
@@ -28,7 +29,7 @@ pub fn process_claim_ore(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
     if recipient_info.data_is_empty() {
         create_associated_token_account(
             signer_info,
-            signer_info,
+            owner_info,
             recipient_info,
             mint_info,
             system_program,
@@ -36,11 +37,12 @@ pub fn process_claim_ore(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
             associated_token_program,
         )?;
     } else {
-        recipient_info.as_associated_token_account(signer_info.key, mint_info.key)?;
+        recipient_info.as_associated_token_account(owner_info.key, mint_info.key)?;
     }
 
     // Normalize amount.
-    let amount = miner.claim_ore(&clock, treasury);
+    let fee_discount_bps = miner.claim_fee_discount_bps;
+    let amount = miner.claim_ore(&clock, treasury, fee_discount_bps);
 
     sol_log(
         &format!(
@@ -0,0 +1,98 @@
+use ore_api::prelude::*;
+use solana_program::log::sol_log;
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+use crate::allowlist::check_allowlist;
+
+/// Claims a block reward straight into the miner's stake account.
+pub fn process_claim_ore_to_stake(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, allowlist_info, config_info, miner_info, mint_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    let miner = miner_info
+        .as_account_mut::<Miner>(&ore_api::ID)?
+        .assert_mut(|m| m.authority == *signer_info.key)?;
+    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    stake_info.is_writable()?;
+    stake_tokens_info.is_writable()?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+    treasury_tokens_info.as_associated_token_account(&treasury_info.key, &mint_info.key)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // Check allowlist.
+    check_allowlist(config, allowlist_info, signer_info.key)?;
+
+    // Open stake account.
+    let stake = if stake_info.data_is_empty() {
+        create_program_account::<Stake>(
+            stake_info,
+            system_program,
+            &signer_info,
+            &ore_api::ID,
+            &[STAKE, &signer_info.key.to_bytes()],
+        )?;
+        let stake = stake_info.as_account_mut::<Stake>(&ore_api::ID)?;
+        stake.initialize(*signer_info.key, treasury);
+        stake
+    } else {
+        stake_info
+            .as_account_mut::<Stake>(&ore_api::ID)?
+            .assert_mut(|s| s.authority == *signer_info.key)?
+    };
+
+    // Create stake tokens account.
+    if stake_tokens_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            stake_info,
+            stake_tokens_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    }
+
+    // Claim rewards from miner account, with a discounted claim fee.
+    let fee_discount_bps = config.stake_claim_fee_discount_bps + miner.claim_fee_discount_bps;
+    let amount = miner.claim_ore(&clock, treasury, fee_discount_bps);
+
+    // Credit rewards to stake account.
+    let amount = stake.deposit_claimed(amount, &clock, config, treasury);
+
+    sol_log(
+        &format!(
+            "Claiming {} ORE to stake",
+            amount_to_ui_amount(amount, TOKEN_DECIMALS)
+        )
+        .as_str(),
+    );
+
+    // Transfer reward to stake tokens account.
+    transfer_signed(
+        treasury_info,
+        treasury_tokens_info,
+        stake_tokens_info,
+        token_program,
+        amount,
+        &[TREASURY],
+    )?;
+
+    // Safety check.
+    let stake_tokens =
+        stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    assert!(stake_tokens.amount() >= stake.balance + stake.pending_withdrawal);
+
+    Ok(())
+}
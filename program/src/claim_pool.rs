@@ -0,0 +1,77 @@
+use ore_api::prelude::*;
+use solana_program::{log::sol_log, native_token::lamports_to_sol};
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+/// Claims a member's SOL and ORE rewards from a mining pool. If the signer is the pool operator,
+/// operator fees are claimed as well.
+pub fn process_claim_pool(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, member_info, mint_info, pool_info, pool_tokens_info, recipient_info, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?.is_writable()?;
+    let member = member_info
+        .as_account_mut::<PoolMember>(&ore_api::ID)?
+        .assert_mut(|m| m.authority == *signer_info.key)?
+        .assert_mut(|m| m.pool == *pool_info.key)?;
+    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    let pool = pool_info.as_account_mut::<Pool>(&ore_api::ID)?;
+    recipient_info.is_writable()?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // Claim member rewards.
+    let (mut amount_sol, mut amount_ore) = member.claim(pool);
+
+    // Claim operator fees.
+    if pool.operator == *signer_info.key {
+        amount_sol += pool.operator_rewards_sol;
+        amount_ore += pool.operator_rewards_ore;
+        pool.operator_rewards_sol = 0;
+        pool.operator_rewards_ore = 0;
+    }
+
+    sol_log(
+        &format!(
+            "Claiming {} SOL and {} ORE",
+            lamports_to_sol(amount_sol),
+            amount_to_ui_amount(amount_ore, TOKEN_DECIMALS)
+        )
+        .as_str(),
+    );
+
+    // Transfer SOL to signer.
+    pool_info.send(amount_sol, signer_info);
+
+    // Transfer ORE to recipient.
+    if amount_ore > 0 {
+        pool_tokens_info.as_associated_token_account(pool_info.key, mint_info.key)?;
+        if recipient_info.data_is_empty() {
+            create_associated_token_account(
+                signer_info,
+                signer_info,
+                recipient_info,
+                mint_info,
+                system_program,
+                token_program,
+                associated_token_program,
+            )?;
+        } else {
+            recipient_info.as_associated_token_account(signer_info.key, mint_info.key)?;
+        }
+        transfer_signed(
+            pool_info,
+            pool_tokens_info,
+            recipient_info,
+            token_program,
+            amount_ore,
+            &[POOL, &pool.operator.to_bytes()],
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use ore_api::prelude::*;
+use solana_program::{log::sol_log, native_token::lamports_to_sol};
+use steel::*;
+
+/// Claims SOL earned from referred miners.
+pub fn process_claim_referral(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, referrer_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?.is_writable()?;
+    let referrer = referrer_info
+        .as_account_mut::<Referrer>(&ore_api::ID)?
+        .assert_mut(|r| r.authority == *signer_info.key)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Claim referral rewards.
+    let amount = referrer.claim_sol(&clock);
+
+    sol_log(&format!("Claiming {} SOL", lamports_to_sol(amount)).as_str());
+
+    // Transfer SOL to signer.
+    referrer_info.send(amount, signer_info);
+
+    Ok(())
+}
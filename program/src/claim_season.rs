@@ -0,0 +1,90 @@
+use ore_api::prelude::*;
+use solana_program::{log::sol_log, native_token::lamports_to_sol};
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+/// Claims a ranked miner's share of the season reward pool, once the season has ended.
+pub fn process_claim_season(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = ClaimSeason::try_from_bytes(data)?;
+    let season_id = u64::from_le_bytes(args.season_id);
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, mint_info, recipient_info, season_info, season_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?.is_writable()?;
+    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    recipient_info.is_writable()?;
+    let season = season_info
+        .as_account::<Season>(&ore_api::ID)?
+        .assert(|s| s.id == season_id)?
+        .assert_err(
+            |s| s.claimable_at > 0 && clock.slot >= s.claimable_at,
+            OreError::SeasonInProgress.into(),
+        )?;
+    let season_miner = season_miner_info
+        .as_account_mut::<SeasonMiner>(&ore_api::ID)?
+        .assert_mut(|m| m.season_id == season_id)?
+        .assert_mut(|m| m.authority == *signer_info.key)?
+        .assert_mut_err(|m| m.claimed == 0, OreError::SeasonAlreadyClaimed.into())?;
+    treasury_info.has_address(&TREASURY_ADDRESS)?;
+    treasury_tokens_info
+        .is_writable()?
+        .as_associated_token_account(treasury_info.key, mint_info.key)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // Calculate rewards.
+    let Some(rank) = season.rank(*signer_info.key) else {
+        return Err(OreError::NotRanked.into());
+    };
+    let (amount_sol, amount_ore) = season.rewards(rank);
+    season_miner.claimed = 1;
+
+    sol_log(
+        &format!(
+            "Rank {}: claiming {} SOL and {} ORE",
+            rank + 1,
+            lamports_to_sol(amount_sol),
+            amount_to_ui_amount(amount_ore, TOKEN_DECIMALS)
+        )
+        .as_str(),
+    );
+
+    // Transfer SOL to signer.
+    if amount_sol > 0 {
+        season_info.send(amount_sol, signer_info);
+    }
+
+    // Transfer ORE to recipient.
+    if amount_ore > 0 {
+        if recipient_info.data_is_empty() {
+            create_associated_token_account(
+                signer_info,
+                signer_info,
+                recipient_info,
+                mint_info,
+                system_program,
+                token_program,
+                associated_token_program,
+            )?;
+        } else {
+            recipient_info.as_associated_token_account(signer_info.key, mint_info.key)?;
+        }
+        transfer_signed(
+            treasury_info,
+            treasury_tokens_info,
+            recipient_info,
+            token_program,
+            amount_ore,
+            &[TREASURY],
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,8 +1,4 @@
-use ore_api::{
-    consts::{SEEKER, STAKE},
-    state::{Seeker, Stake},
-};
-use solana_program::pubkey;
+use ore_api::prelude::*;
 use steel::*;
 
 use spl_token_2022::{
@@ -12,23 +8,28 @@ use spl_token_2022::{
     pod::{PodCOption, PodMint},
 };
 
-/// Claims a Seeker genesis token for a miner.
+/// Claims a token from a registered collection, such as a Seeker genesis token, for its perk.
 pub fn process_claim_seeker(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
-    let [signer_info, mint_info, seeker_info, stake_info, token_account_info, system_program] =
+    let [signer_info, collection_info, config_info, mint_info, miner_info, seeker_info, stake_info, token_account_info, treasury_info, system_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
-    mint_info.has_owner(&spl_token_2022::ID)?;
+    let collection = collection_info.as_account_mut::<Collection>(&ore_api::ID)?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?.assert_err(
+        |c| c.is_seeker_activation_enabled == 1,
+        OreError::ClaimsDisabled.into(),
+    )?;
+    mint_info.has_owner(&collection.token_program)?;
     seeker_info
         .is_writable()?
         .has_seeds(&[SEEKER, &mint_info.key.to_bytes()], &ore_api::ID)?;
-    stake_info.is_writable()?;
     token_account_info
         .as_associated_token_account(signer_info.key, mint_info.key)?
         .assert(|t| t.amount() == 1)?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
     system_program.is_program(&system_program::ID)?;
 
     // Load mint.
@@ -36,25 +37,22 @@ pub fn process_claim_seeker(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Progr
     let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
 
     // Check mint authority.
-    assert!(
-        mint.base.mint_authority
-            == PodCOption::some(pubkey!("GT2zuHVaZQYZSyQMgJPLzvkmyztfyXg2NJunqFp4p3A4")),
-        "mint authority mismatch"
-    );
+    if mint.base.mint_authority != PodCOption::some(collection.mint_authority) {
+        return Err(OreError::IneligibleToken.into());
+    }
 
     // Check metadata pointer.
-    let ext = mint.get_extension::<MetadataPointer>()?;
-    assert!(
-        ext.authority.0 == pubkey!("GT2zuHVaZQYZSyQMgJPLzvkmyztfyXg2NJunqFp4p3A4"),
-        "metadata authority mismatch"
-    );
-    assert!(
-        ext.metadata_address.0 == pubkey!("GT22s89nU4iWFkNXj1Bw6uYhJJWDRPpShHt4Bk8f99Te"),
-        "metadata address mismatch"
-    );
+    if collection.metadata_address != Pubkey::default() {
+        let ext = mint.get_extension::<MetadataPointer>()?;
+        if ext.authority.0 != collection.mint_authority
+            || ext.metadata_address.0 != collection.metadata_address
+        {
+            return Err(OreError::IneligibleToken.into());
+        }
+    }
 
     // Open seeker account.
-    // Each genesis token can only be claimed once.
+    // Each token can only be claimed once.
     if !seeker_info.data_is_empty() {
         return Ok(());
     }
@@ -67,36 +65,53 @@ pub fn process_claim_seeker(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Progr
     )?;
     let seeker = seeker_info.as_account_mut::<Seeker>(&ore_api::ID)?;
     seeker.mint = *mint_info.key;
+    seeker.collection = *collection_info.key;
+    collection.total_claimed += 1;
 
-    // Open stake account.
-    let stake = if stake_info.data_is_empty() {
-        create_program_account::<Stake>(
-            stake_info,
-            system_program,
-            signer_info,
-            &ore_api::ID,
-            &[STAKE, &signer_info.key.to_bytes()],
-        )?;
-        let stake = stake_info.as_account_mut::<Stake>(&ore_api::ID)?;
-        stake.authority = *signer_info.key;
-        stake.balance = 0;
-        stake.last_claim_at = 0;
-        stake.last_deposit_at = 0;
-        stake.last_withdraw_at = 0;
-        stake.rewards_factor = Numeric::from_u64(0);
-        stake.rewards = 0;
-        stake.lifetime_rewards = 0;
-        stake.is_seeker = 0;
-        stake
-    } else {
-        stake_info
-            .as_account_mut::<Stake>(&ore_api::ID)?
-            .assert_mut(|s| s.authority == *signer_info.key)?
-            .assert_mut(|s| s.is_seeker == 0)?
-    };
+    // Grant the collection perk.
+    match collection.perk() {
+        CollectionPerk::StakeMultiplier => {
+            // Open stake account.
+            stake_info.is_writable()?;
+            let stake = if stake_info.data_is_empty() {
+                create_program_account::<Stake>(
+                    stake_info,
+                    system_program,
+                    signer_info,
+                    &ore_api::ID,
+                    &[STAKE, &signer_info.key.to_bytes()],
+                )?;
+                let stake = stake_info.as_account_mut::<Stake>(&ore_api::ID)?;
+                stake.initialize(*signer_info.key, treasury);
+                stake
+            } else {
+                stake_info
+                    .as_account_mut::<Stake>(&ore_api::ID)?
+                    .assert_mut(|s| s.authority == *signer_info.key)?
+                    .assert_mut_err(
+                        |s| s.is_seeker == 0 && s.collection_multiplier_bps == 0,
+                        OreError::PerkAlreadyClaimed.into(),
+                    )?
+            };
 
-    // Flag the miner as a Seeker.
-    stake.is_seeker = 1;
+            // Flag the staker as a Seeker, and apply the collection multiplier to its weight.
+            stake.update_rewards(treasury);
+            stake.is_seeker = 1;
+            stake.collection_multiplier_bps = collection.perk_bps;
+            stake.update_weight(config, treasury);
+        }
+        CollectionPerk::ClaimFeeDiscount => {
+            // Discount the miner's ORE claim fee.
+            let miner = miner_info
+                .as_account_mut::<Miner>(&ore_api::ID)?
+                .assert_mut(|m| m.authority == *signer_info.key)?
+                .assert_mut_err(
+                    |m| m.claim_fee_discount_bps == 0,
+                    OreError::PerkAlreadyClaimed.into(),
+                )?;
+            miner.claim_fee_discount_bps = collection.perk_bps;
+        }
+    }
 
     Ok(())
 }
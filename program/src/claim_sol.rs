@@ -6,13 +6,16 @@ use steel::*;
 pub fn process_claim_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, miner_info, system_program] = accounts else {
+    let [signer_info, miner_info, recipient_info, system_program] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
     let miner = miner_info
         .as_account_mut::<Miner>(&ore_api::ID)?
-        .assert_mut(|m| m.authority == *signer_info.key)?;
+        .assert_mut(|m| m.can_claim(signer_info.key))?;
+    recipient_info
+        .is_writable()?
+        .has_address(&miner.payout_recipient())?;
     system_program.is_program(&system_program::ID)?;
 
     // Normalize amount.
@@ -21,7 +24,7 @@ pub fn process_claim_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
     sol_log(&format!("Claiming {} SOL", lamports_to_sol(amount)).as_str());
 
     // Transfer reward to recipient.
-    miner_info.send(amount, signer_info);
+    miner_info.send(amount, recipient_info);
 
     Ok(())
 }
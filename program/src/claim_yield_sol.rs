@@ -0,0 +1,33 @@
+use ore_api::prelude::*;
+use solana_program::{log::sol_log, native_token::lamports_to_sol};
+use steel::*;
+
+/// Claims SOL yield from the staking contract.
+pub fn process_claim_yield_sol(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = ClaimYieldSol::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, stake_info, treasury_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?.is_writable()?;
+    let stake = stake_info
+        .as_account_mut::<Stake>(&ore_api::ID)?
+        .assert_mut(|s| s.authority == *signer_info.key)?;
+    let treasury = treasury_info.as_account::<Treasury>(&ore_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Claim SOL yield from stake account.
+    let amount = stake.claim_sol(amount, &clock, treasury);
+
+    // Log claim.
+    sol_log(&format!("Claiming {} SOL", lamports_to_sol(amount)).as_str());
+
+    // Transfer SOL to recipient.
+    treasury_info.send(amount, signer_info);
+
+    Ok(())
+}
@@ -6,21 +6,23 @@ use steel::*;
 pub fn process_close(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, board_info, rent_payer_info, round_info, treasury_info, system_program] =
+    let [signer_info, board_info, config_info, rent_payer_info, round_info, treasury_info, system_program, ore_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
-    signer_info.is_signer()?;
+    signer_info.is_signer()?.is_writable()?;
     let board = board_info.as_account_mut::<Board>(&ore_api::ID)?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
     rent_payer_info.is_writable()?;
-    round_info
+    let round = round_info
         .as_account_mut::<Round>(&ore_api::ID)?
         .assert_mut(|r| r.id < board.round_id)?
         .assert_mut(|r| r.expires_at < clock.slot)? // Ensure round has expired.
         .assert_mut(|r| r.rent_payer == *rent_payer_info.key)?; // Ensure the rent payer is the correct one.
     let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
     system_program.is_program(&system_program::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
 
     // Vault all unclaimed rewards.
     let size = 8 + std::mem::size_of::<Round>();
@@ -28,7 +30,32 @@ pub fn process_close(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     let unclaimed_sol = round_info.lamports() - min_rent;
     if unclaimed_sol > 0 {
         round_info.send(unclaimed_sol, treasury_info);
-        treasury.balance += unclaimed_sol;
+        treasury.vault_unclaimed_sol(unclaimed_sol, config.close_destination());
+    }
+
+    // Roll sponsored ORE that was never checkpointed into the motherlode.
+    // The ORE is already held in the treasury token account.
+    if round.unclaimed_bonus_ore > 0 {
+        treasury.motherlode += round.unclaimed_bonus_ore;
+    }
+
+    // Pay crank bounty.
+    let bounty = config.close_bounty.min(treasury.balance);
+    if bounty > 0 {
+        treasury.balance -= bounty;
+        treasury_info.send(bounty, signer_info);
+        program_log(
+            &[board_info.clone(), ore_program.clone()],
+            BountyEvent {
+                disc: 3,
+                round_id: round.id,
+                kind: BountyKind::Close as u64,
+                authority: *signer_info.key,
+                amount: bounty,
+                ts: clock.unix_timestamp,
+            }
+            .to_bytes(),
+        )?;
     }
 
     // Close the account.
@@ -0,0 +1,31 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Closes a miner account, and returns the rent and any residual checkpoint fee to the authority.
+pub fn process_close_miner(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, automation_info, miner_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    automation_info
+        .is_empty()?
+        .has_seeds(&[AUTOMATION, &signer_info.key.to_bytes()], &ore_api::ID)?;
+    miner_info
+        .as_account::<Miner>(&ore_api::ID)?
+        .assert(|m| m.authority == *signer_info.key)?
+        .assert_err(
+            |m| m.checkpoint_id == m.round_id,
+            OreError::NotCheckpointed.into(),
+        )?
+        .assert_err(
+            |m| m.rewards_sol == 0 && m.rewards_ore == 0 && m.refined_ore == 0,
+            OreError::AccountNotEmpty.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Close the account.
+    miner_info.close(signer_info)?;
+
+    Ok(())
+}
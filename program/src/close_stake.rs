@@ -0,0 +1,59 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Closes a stake account and its token account, and returns the rent to the authority.
+pub fn process_close_stake(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, mint_info, stake_info, stake_tokens_info, treasury_info, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    let stake = stake_info
+        .as_account_mut::<Stake>(&ore_api::ID)?
+        .assert_mut(|s| s.authority == *signer_info.key)?;
+    stake_tokens_info.is_writable()?;
+    let treasury = treasury_info.as_account::<Treasury>(&ore_api::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+
+    // Ensure there are no outstanding balances or rewards.
+    stake.update_rewards(treasury);
+    if stake.balance > 0
+        || stake.pending_withdrawal > 0
+        || stake.rewards > 0
+        || stake.rewards_sol > 0
+    {
+        return Err(OreError::AccountNotEmpty.into());
+    }
+
+    // Close the stake tokens account.
+    if !stake_tokens_info.data_is_empty() {
+        stake_tokens_info
+            .as_associated_token_account(stake_info.key, mint_info.key)?
+            .assert_err(|t| t.amount() == 0, OreError::AccountNotEmpty.into())?;
+        invoke_signed(
+            &spl_token::instruction::close_account(
+                &spl_token::ID,
+                stake_tokens_info.key,
+                signer_info.key,
+                stake_info.key,
+                &[],
+            )?,
+            &[
+                stake_tokens_info.clone(),
+                signer_info.clone(),
+                stake_info.clone(),
+                token_program.clone(),
+            ],
+            &ore_api::ID,
+            &[STAKE, &signer_info.key.to_bytes()],
+        )?;
+    }
+
+    // Close the stake account.
+    stake_info.close(signer_info)?;
+
+    Ok(())
+}
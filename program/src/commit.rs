@@ -0,0 +1,32 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Commits to a secret for the current round's entropy.
+pub fn process_commit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Commit::try_from_bytes(data)?;
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, board_info, config_info, round_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let board = board_info
+        .as_account::<Board>(&ore_api::ID)?
+        .assert(|b| clock.slot < b.end_slot)?;
+    config_info.as_account::<Config>(&ore_api::ID)?.assert_err(
+        |c| c.randomness_authority == *signer_info.key,
+        OreError::NotAuthorized.into(),
+    )?;
+    let round = round_info
+        .as_account_mut::<Round>(&ore_api::ID)?
+        .assert_mut(|r| r.id == board.round_id)?
+        .assert_mut(|r| r.entropy_source() == EntropySource::CommitReveal)?
+        .assert_mut(|r| r.entropy_commit == [0; 32])?;
+
+    // Record commitment.
+    round.entropy_commit = args.commit;
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use ore_api::prelude::*;
+use solana_program::log::sol_log;
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+/// Compounds staking yield into the stake balance.
+pub fn process_compound_yield(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, mint_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    let stake = stake_info
+        .as_account_mut::<Stake>(&ore_api::ID)?
+        .assert_mut_err(
+            |s| s.authority == *signer_info.key || s.auto_compound == 1,
+            OreError::NotAuthorized.into(),
+        )?;
+    stake_tokens_info
+        .is_writable()?
+        .as_associated_token_account(stake_info.key, mint_info.key)?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+    treasury_tokens_info
+        .is_writable()?
+        .as_associated_token_account(&treasury_info.key, &mint_info.key)?;
+    token_program.is_program(&spl_token::ID)?;
+
+    // Compound yield into stake account.
+    let amount = stake.compound(&clock, config, treasury);
+
+    // Transfer ORE to stake tokens account.
+    transfer_signed(
+        treasury_info,
+        treasury_tokens_info,
+        stake_tokens_info,
+        token_program,
+        amount,
+        &[TREASURY],
+    )?;
+
+    // Log compound.
+    sol_log(
+        &format!(
+            "Compounding {} ORE",
+            amount_to_ui_amount(amount, TOKEN_DECIMALS)
+        )
+        .as_str(),
+    );
+
+    // Safety check.
+    let stake_tokens =
+        stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    assert!(stake_tokens.amount() >= stake.balance + stake.pending_withdrawal);
+
+    Ok(())
+}
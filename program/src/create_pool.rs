@@ -0,0 +1,48 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Creates a mining pool operated by the signer.
+pub fn process_create_pool(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = CreatePool::try_from_bytes(data)?;
+    let fee_bps = u64::from_le_bytes(args.fee_bps);
+
+    // Load accounts.
+    let [signer_info, pool_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    pool_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[POOL, &signer_info.key.to_bytes()], &ore_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate fee.
+    if fee_bps > MAX_POOL_FEE_BPS {
+        return Err(OreError::PoolFeeTooHigh.into());
+    }
+
+    // Open pool account.
+    create_program_account::<Pool>(
+        pool_info,
+        system_program,
+        signer_info,
+        &ore_api::ID,
+        &[POOL, &signer_info.key.to_bytes()],
+    )?;
+    let pool = pool_info.as_account_mut::<Pool>(&ore_api::ID)?;
+    pool.operator = *signer_info.key;
+    pool.fee_bps = fee_bps;
+    pool.balance = 0;
+    pool.sol_rewards_factor = Numeric::ZERO;
+    pool.ore_rewards_factor = Numeric::ZERO;
+    pool.total_shares = 0;
+    pool.operator_rewards_sol = 0;
+    pool.operator_rewards_ore = 0;
+    pool.lifetime_rewards_sol = 0;
+    pool.lifetime_rewards_ore = 0;
+    pool.deployed = 0;
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Creates the next season, once the previous season has ended.
+pub fn process_create_season(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = CreateSeason::try_from_bytes(data)?;
+    let start_round = u64::from_le_bytes(args.start_round);
+    let end_round = u64::from_le_bytes(args.end_round);
+
+    // Load accounts.
+    let [signer_info, board_info, config_info, season_info, prev_season_info, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let board = board_info.as_account::<Board>(&ore_api::ID)?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    let season_id = config.season_id + 1;
+    season_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[SEASON, &season_id.to_le_bytes()], &ore_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate season rounds. Rounds already in progress cannot join a season.
+    if start_round <= board.round_id || end_round < start_round {
+        return Err(OreError::InvalidSeason.into());
+    }
+
+    // Seasons run one at a time.
+    if config.season_id > 0 {
+        prev_season_info
+            .as_account::<Season>(&ore_api::ID)?
+            .assert(|s| s.id == config.season_id)?
+            .assert_err(
+                |s| board.round_id > s.end_round,
+                OreError::SeasonInProgress.into(),
+            )?;
+    }
+
+    // Open season account.
+    create_program_account::<Season>(
+        season_info,
+        system_program,
+        signer_info,
+        &ore_api::ID,
+        &[SEASON, &season_id.to_le_bytes()],
+    )?;
+    let season = season_info.as_account_mut::<Season>(&ore_api::ID)?;
+    season.id = season_id;
+    season.start_round = start_round;
+    season.end_round = end_round;
+    season.reward_sol = 0;
+    season.reward_ore = 0;
+    season.claimable_at = 0;
+    season.total_miners = 0;
+    season.leaderboard = [LeaderboardEntry::zeroed(); SEASON_LEADERBOARD_SIZE];
+
+    // Set current season.
+    config.season_id = season_id;
+
+    Ok(())
+}
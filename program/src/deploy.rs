@@ -2,22 +2,32 @@ use ore_api::prelude::*;
 use solana_program::{keccak::hashv, log::sol_log, native_token::lamports_to_sol};
 use steel::*;
 
+use crate::{allowlist::check_allowlist, migrate_account};
+
 /// Deploys capital to prospect on a square.
 pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = Deploy::try_from_bytes(data)?;
     let mut amount = u64::from_le_bytes(args.amount);
-    let mask = u32::from_le_bytes(args.squares);
+    let mask = u64::from_le_bytes(args.squares);
+    let referrer = Pubkey::new_from_array(args.referrer);
 
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, authority_info, automation_info, board_info, miner_info, round_info, system_program] =
+    let [signer_info, authority_info, allowlist_info, automation_info, board_info, config_info, miner_info, referrer_info, round_info, season_miner_info, system_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
     authority_info.is_writable()?;
+
+    // Migrate accounts created before the board size was configurable.
+    migrate_account::<Round>(round_info, signer_info)?;
+    if !miner_info.data_is_empty() {
+        migrate_account::<Miner>(miner_info, signer_info)?;
+    }
+
     automation_info
         .is_writable()?
         .has_seeds(&[AUTOMATION, &authority_info.key.to_bytes()], &ore_api::ID)?;
@@ -27,6 +37,7 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
     let round = round_info
         .as_account_mut::<Round>(&ore_api::ID)?
         .assert_mut(|r| r.id == board.round_id)?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
     miner_info
         .is_writable()?
         .has_seeds(&[MINER, &authority_info.key.to_bytes()], &ore_api::ID)?;
@@ -50,8 +61,28 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         None
     };
 
+    // Check if the authority is a mining pool, deployed by its operator.
+    let pool = if authority_info.owner == &ore_api::ID {
+        let pool = authority_info
+            .as_account_mut::<Pool>(&ore_api::ID)?
+            .assert_mut(|p| p.operator == *signer_info.key)?;
+        Some(pool)
+    } else {
+        None
+    };
+    let miner_authority = if pool.is_some() {
+        *authority_info.key
+    } else {
+        *signer_info.key
+    };
+
+    // Check allowlist. Automation executors deploy on behalf of the automation authority.
+    let deploy_authority = automation.as_ref().map_or(miner_authority, |a| a.authority);
+    check_allowlist(config, allowlist_info, &deploy_authority)?;
+
     // Update amount and mask for automation.
-    let mut squares = [false; 25];
+    let num_squares = round.num_squares();
+    let mut squares = [false; MAX_SQUARES];
     if let Some(automation) = &automation {
         // Set amount
         amount = automation.amount;
@@ -60,25 +91,33 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         match AutomationStrategy::from_u64(automation.strategy as u64) {
             AutomationStrategy::Preferred => {
                 // Preferred automation strategy. Use the miner authority's provided mask.
-                for i in 0..25 {
+                for i in 0..num_squares {
                     squares[i] = (automation.mask & (1 << i)) != 0;
                 }
             }
             AutomationStrategy::Random => {
                 // Random automation strategy. Generate a random mask based on number of squares user wants to deploy to.
-                let num_squares = ((automation.mask & 0xFF) as u64).min(25);
+                let num_deploys = ((automation.mask & 0xFF) as u64).min(num_squares as u64);
                 let r = hashv(&[&automation.authority.to_bytes(), &round.id.to_le_bytes()]).0;
-                squares = generate_random_mask(num_squares, &r);
+                squares = generate_random_mask(num_deploys, num_squares, &r);
             }
         }
     } else {
-        // Convert provided 32-bit mask into array of 25 booleans, where each bit in the mask
+        // Convert provided 64-bit mask into array of booleans, where each bit in the mask
         // determines if that square index is selected (true) or not (false)
-        for i in 0..25 {
+        for i in 0..num_squares {
             squares[i] = (mask & (1 << i)) != 0;
         }
     }
 
+    // Enforce per-square deploy limits.
+    if amount < config.min_deploy_amount {
+        return Err(OreError::AmountTooSmall.into());
+    }
+    if config.max_deploy_per_square > 0 && amount > config.max_deploy_per_square {
+        return Err(OreError::DeployExceedsSquareLimit.into());
+    }
+
     // Open miner account.
     let miner = if miner_info.data_is_empty() {
         create_program_account::<Miner>(
@@ -86,12 +125,12 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
             system_program,
             signer_info,
             &ore_api::ID,
-            &[MINER, &signer_info.key.to_bytes()],
+            &[MINER, &miner_authority.to_bytes()],
         )?;
         let miner = miner_info.as_account_mut::<Miner>(&ore_api::ID)?;
-        miner.authority = *signer_info.key;
-        miner.deployed = [0; 25];
-        miner.cumulative = [0; 25];
+        miner.authority = miner_authority;
+        miner.deployed = [0; MAX_SQUARES];
+        miner.cumulative = [0; MAX_SQUARES];
         miner.rewards_sol = 0;
         miner.rewards_ore = 0;
         miner.round_id = 0;
@@ -106,7 +145,7 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
                 if let Some(automation) = &automation {
                     m.authority == automation.authority
                 } else {
-                    m.authority == *signer_info.key
+                    m.authority == miner_authority
                 }
             })?
     };
@@ -120,17 +159,72 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         );
 
         // Reset miner for new round.
-        miner.deployed = [0; 25];
+        miner.deployed = [0; MAX_SQUARES];
         miner.cumulative = round.deployed;
         miner.round_id = round.id;
     }
 
+    // Set referrer, once. Automation executors cannot set a referrer on the authority's behalf.
+    // The miner opens the referrer account, so checkpoints never pay its rent.
+    if miner.referrer == Pubkey::default()
+        && automation.is_none()
+        && referrer != Pubkey::default()
+        && referrer != miner.authority
+    {
+        referrer_info
+            .is_writable()?
+            .has_seeds(&[REFERRER, &referrer.to_bytes()], &ore_api::ID)?;
+        if referrer_info.data_is_empty() {
+            create_program_account::<Referrer>(
+                referrer_info,
+                system_program,
+                signer_info,
+                &ore_api::ID,
+                &[REFERRER, &referrer.to_bytes()],
+            )?;
+            let referrer_account = referrer_info.as_account_mut::<Referrer>(&ore_api::ID)?;
+            referrer_account.authority = referrer;
+            referrer_account.rewards_sol = 0;
+            referrer_account.lifetime_rewards_sol = 0;
+            referrer_account.last_claim_at = 0;
+        }
+        miner.referrer = referrer;
+        round.total_referred += miner.deployed.iter().sum::<u64>();
+    }
+
+    // Open the miner's season account, so checkpoints never pay its rent.
+    if round.season_id > 0 {
+        let season_id = round.season_id.to_le_bytes();
+        season_miner_info.is_writable()?.has_seeds(
+            &[SEASON_MINER, &season_id, &miner.authority.to_bytes()],
+            &ore_api::ID,
+        )?;
+        if season_miner_info.data_is_empty() {
+            create_program_account::<SeasonMiner>(
+                season_miner_info,
+                system_program,
+                signer_info,
+                &ore_api::ID,
+                &[SEASON_MINER, &season_id, &miner.authority.to_bytes()],
+            )?;
+            let season_miner = season_miner_info.as_account_mut::<SeasonMiner>(&ore_api::ID)?;
+            season_miner.season_id = round.season_id;
+            season_miner.authority = miner.authority;
+            season_miner.sol_deployed = 0;
+            season_miner.sol_won = 0;
+            season_miner.ore_won = 0;
+            season_miner.rounds_played = 0;
+            season_miner.claimed = 0;
+        }
+    }
+
     // Calculate all deployments.
+    let deployed_before = miner.deployed.iter().sum::<u64>();
     let mut total_amount = 0;
     let mut total_squares = 0;
     for (square_id, &should_deploy) in squares.iter().enumerate() {
         // Skip if square index is out of bounds.
-        if square_id > 24 {
+        if square_id >= num_squares {
             break;
         }
 
@@ -144,6 +238,16 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
             continue;
         }
 
+        // Enforce per-round deploy limit. Automations deploy up to the limit.
+        if config.max_deploy_per_round > 0
+            && deployed_before + total_amount + amount > config.max_deploy_per_round
+        {
+            if automation.is_some() {
+                break;
+            }
+            return Err(OreError::DeployExceedsRoundLimit.into());
+        }
+
         // Record cumulative amount.
         miner.cumulative[square_id] = round.deployed[square_id];
 
@@ -167,6 +271,11 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         }
     }
 
+    // Track referred deployments.
+    if miner.referrer != Pubkey::default() {
+        round.total_referred += total_amount;
+    }
+
     // Top up checkpoint fee.
     if miner.checkpoint_fee == 0 {
         miner.checkpoint_fee = CHECKPOINT_FEE;
@@ -183,6 +292,12 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         if automation.balance < automation.amount + automation.fee {
             automation_info.close(authority_info)?;
         }
+    } else if let Some(pool) = pool {
+        if total_amount > pool.balance {
+            return Err(OreError::InsufficientPoolBalance.into());
+        }
+        pool.deploy(total_amount);
+        authority_info.send(total_amount, &round_info);
     } else {
         round_info.collect(total_amount, &signer_info)?;
     }
@@ -201,13 +316,22 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
     Ok(())
 }
 
-fn generate_random_mask(num_squares: u64, r: &[u8]) -> [bool; 25] {
-    let mut new_mask = [false; 25];
+fn generate_random_mask(
+    num_deploys: u64,
+    num_squares: usize,
+    seed: &[u8; 32],
+) -> [bool; MAX_SQUARES] {
+    // Extend the seed with its hash, so there is a random byte for every square.
+    let mut r = [0; MAX_SQUARES];
+    r[..32].copy_from_slice(seed);
+    r[32..].copy_from_slice(&hashv(&[seed]).0);
+
+    let mut new_mask = [false; MAX_SQUARES];
     let mut selected = 0;
-    for i in 0..25 {
+    for i in 0..num_squares {
         let rand_byte = r[i];
-        let remaining_needed = num_squares as u64 - selected as u64;
-        let remaining_positions = 25 - i;
+        let remaining_needed = num_deploys - selected as u64;
+        let remaining_positions = num_squares - i;
         if remaining_needed > 0
             && (rand_byte as u64) * (remaining_positions as u64) < (remaining_needed * 256)
         {
@@ -3,20 +3,27 @@ use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::allowlist::check_allowlist;
+
 /// Deposits ORE into the staking contract.
 pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = Deposit::try_from_bytes(data)?;
     let amount = u64::from_le_bytes(args.amount);
+    let lock_tier = u64::from_le_bytes(args.lock_tier);
+    if lock_tier as usize >= LOCK_TIER_DURATIONS.len() {
+        return Err(OreError::InvalidLockTier.into());
+    }
 
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, mint_info, sender_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
+    let [signer_info, allowlist_info, config_info, mint_info, sender_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
     mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
     let sender = sender_info
         .is_writable()?
@@ -27,6 +34,9 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     token_program.is_program(&spl_token::ID)?;
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
 
+    // Check allowlist.
+    check_allowlist(config, allowlist_info, signer_info.key)?;
+
     // Open stake account.
     let stake = if stake_info.data_is_empty() {
         create_program_account::<Stake>(
@@ -37,15 +47,7 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
             &[STAKE, &signer_info.key.to_bytes()],
         )?;
         let stake = stake_info.as_account_mut::<Stake>(&ore_api::ID)?;
-        stake.authority = *signer_info.key;
-        stake.balance = 0;
-        stake.last_claim_at = 0;
-        stake.last_deposit_at = 0;
-        stake.last_withdraw_at = 0;
-        stake.is_seeker = 0;
-        stake.rewards_factor = treasury.stake_rewards_factor;
-        stake.rewards = 0;
-        stake.lifetime_rewards = 0;
+        stake.initialize(*signer_info.key, treasury);
         stake
     } else {
         stake_info
@@ -72,7 +74,7 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     // assert!(stake.is_seeker == 1, "Only seekers can deposit stake");
 
     // Deposit into stake account.
-    let amount = stake.deposit(amount, &clock, treasury, &sender);
+    let amount = stake.deposit(amount, lock_tier, &clock, config, treasury, &sender);
 
     // Transfer ORE to treasury.
     transfer(
@@ -95,7 +97,7 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     // Safety check.
     let stake_tokens =
         stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
-    assert!(stake_tokens.amount() >= stake.balance);
+    assert!(stake_tokens.amount() >= stake.balance + stake.pending_withdrawal);
 
     Ok(())
 }
@@ -0,0 +1,37 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Pays the admin fees accrued in the fee split to its recipients, by weight.
+pub fn process_distribute_fees(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, fee_split_info, recipient_infos @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let fee_split = fee_split_info
+        .is_writable()?
+        .as_account_mut::<FeeSplit>(&ore_api::ID)?;
+    if recipient_infos.len() != fee_split.recipients().len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Pay each recipient its share. Rounding dust is left for the next distribution.
+    let amount = fee_split.balance;
+    let mut total_paid = 0;
+    for (recipient, recipient_info) in fee_split.recipients().iter().zip(recipient_infos) {
+        recipient_info
+            .is_writable()?
+            .has_address(&recipient.address)?;
+        let share = fee_split.share(recipient, amount);
+        if share > 0 {
+            fee_split_info.send(share, recipient_info);
+            total_paid += share;
+        }
+    }
+
+    // Update fee split.
+    fee_split.balance -= total_paid;
+    fee_split.total_distributed += total_paid;
+
+    Ok(())
+}
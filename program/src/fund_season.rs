@@ -0,0 +1,56 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Adds SOL and ORE to a season's reward pool, until the season ends.
+pub fn process_fund_season(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = FundSeason::try_from_bytes(data)?;
+    let season_id = u64::from_le_bytes(args.season_id);
+    let amount_sol = u64::from_le_bytes(args.amount_sol);
+    let amount_ore = u64::from_le_bytes(args.amount_ore);
+    if amount_sol == 0 && amount_ore == 0 {
+        return Err(OreError::AmountTooSmall.into());
+    }
+
+    // Load accounts.
+    let [signer_info, mint_info, season_info, sender_info, treasury_info, treasury_tokens_info, system_program, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    let season = season_info
+        .as_account_mut::<Season>(&ore_api::ID)?
+        .assert_mut(|s| s.id == season_id)?
+        .assert_mut_err(|s| s.claimable_at == 0, OreError::SeasonEnded.into())?;
+    treasury_info.has_address(&TREASURY_ADDRESS)?;
+    treasury_tokens_info
+        .is_writable()?
+        .as_associated_token_account(treasury_info.key, mint_info.key)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+
+    // Hold SOL in the season account.
+    if amount_sol > 0 {
+        season.reward_sol += amount_sol;
+        season_info.collect(amount_sol, signer_info)?;
+    }
+
+    // Hold ORE in the treasury.
+    if amount_ore > 0 {
+        sender_info
+            .is_writable()?
+            .as_associated_token_account(signer_info.key, mint_info.key)?;
+        season.reward_ore += amount_ore;
+        transfer(
+            signer_info,
+            sender_info,
+            treasury_tokens_info,
+            token_program,
+            amount_ore,
+        )?;
+    }
+
+    Ok(())
+}
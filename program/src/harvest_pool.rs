@@ -0,0 +1,75 @@
+use ore_api::prelude::*;
+use solana_program::{log::sol_log, native_token::lamports_to_sol};
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+/// Claims a pool miner's checkpointed rewards into the pool, and distributes them to members.
+pub fn process_harvest_pool(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, miner_info, mint_info, pool_info, pool_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let miner = miner_info
+        .as_account_mut::<Miner>(&ore_api::ID)?
+        .assert_mut(|m| m.authority == *pool_info.key)?
+        .assert_mut_err(
+            |m| m.checkpoint_id == m.round_id,
+            OreError::NotCheckpointed.into(),
+        )?;
+    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    let pool = pool_info.as_account_mut::<Pool>(&ore_api::ID)?;
+    pool_tokens_info.is_writable()?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+    treasury_tokens_info.as_associated_token_account(treasury_info.key, mint_info.key)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // Create pool tokens account.
+    if pool_tokens_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            pool_info,
+            pool_tokens_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        pool_tokens_info.as_associated_token_account(pool_info.key, mint_info.key)?;
+    }
+
+    // Claim miner rewards.
+    let amount_sol = miner.claim_sol(&clock);
+    let amount_ore = miner.claim_ore(&clock, treasury, 0);
+
+    // Move rewards into the pool.
+    miner_info.send(amount_sol, pool_info);
+    transfer_signed(
+        treasury_info,
+        treasury_tokens_info,
+        pool_tokens_info,
+        token_program,
+        amount_ore,
+        &[TREASURY],
+    )?;
+
+    // Return deployed capital to the pool balance, and distribute the profit to members.
+    let profit_sol = pool.harvest(amount_sol, amount_ore);
+
+    sol_log(
+        &format!(
+            "Harvesting {} SOL profit and {} ORE",
+            lamports_to_sol(profit_sol),
+            amount_to_ui_amount(amount_ore, TOKEN_DECIMALS)
+        )
+        .as_str(),
+    );
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use steel::*;
 /// Initializes the program.
 pub fn process_initialize(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
-    let [signer_info, board_info, config_info, mint_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+    let [signer_info, board_info, config_info, history_info, mint_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -12,6 +12,7 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     signer_info.is_signer()?.has_address(&ADMIN_ADDRESS)?;
     board_info.has_seeds(&[BOARD], &ore_api::ID)?;
     config_info.has_seeds(&[CONFIG], &ore_api::ID)?;
+    history_info.has_seeds(&[HISTORY], &ore_api::ID)?;
     mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
     treasury_info.has_seeds(&[TREASURY], &ore_api::ID)?;
     treasury_tokens_info.has_address(&treasury_tokens_address())?;
@@ -56,10 +57,26 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
         config.admin = *signer_info.key;
         config.last_boost = 0;
         config.fee_collector = *signer_info.key;
+        config.max_wrap = DEFAULT_MAX_WRAP;
     } else {
         config_info.as_account::<Config>(&ore_api::ID)?;
     }
 
+    // Create history account.
+    if history_info.data_is_empty() {
+        create_program_account::<History>(
+            history_info,
+            system_program,
+            signer_info,
+            &ore_api::ID,
+            &[HISTORY],
+        )?;
+        let history = history_info.as_account_mut::<History>(&ore_api::ID)?;
+        history.total = 0;
+    } else {
+        history_info.as_account::<History>(&ore_api::ID)?;
+    }
+
     // Create treasury account.
     if treasury_info.data_is_empty() {
         create_program_account::<Treasury>(
@@ -0,0 +1,92 @@
+use ore_api::prelude::*;
+use solana_program::{log::sol_log, native_token::lamports_to_sol};
+use steel::*;
+
+use crate::allowlist::check_allowlist;
+
+/// Deposits SOL into a mining pool in exchange for pool shares.
+pub fn process_join_pool(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = JoinPool::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    // Load accounts.
+    let [signer_info, allowlist_info, config_info, member_info, pool_info, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    member_info.is_writable()?.has_seeds(
+        &[
+            POOL_MEMBER,
+            &pool_info.key.to_bytes(),
+            &signer_info.key.to_bytes(),
+        ],
+        &ore_api::ID,
+    )?;
+    let pool = pool_info
+        .as_account_mut::<Pool>(&ore_api::ID)?
+        .assert_mut_err(|p| p.is_settled(), OreError::PoolCapitalDeployed.into())?
+        .assert_mut_err(
+            |p| p.total_shares == 0 || p.balance > 0,
+            OreError::InsufficientPoolBalance.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Check allowlist. Members take part in the pool's deploys, so they must be allowlisted too.
+    check_allowlist(config, allowlist_info, signer_info.key)?;
+
+    // Open pool member account.
+    let member = if member_info.data_is_empty() {
+        create_program_account::<PoolMember>(
+            member_info,
+            system_program,
+            signer_info,
+            &ore_api::ID,
+            &[
+                POOL_MEMBER,
+                &pool_info.key.to_bytes(),
+                &signer_info.key.to_bytes(),
+            ],
+        )?;
+        let member = member_info.as_account_mut::<PoolMember>(&ore_api::ID)?;
+        member.authority = *signer_info.key;
+        member.pool = *pool_info.key;
+        member.sol_rewards_factor = pool.sol_rewards_factor;
+        member.ore_rewards_factor = pool.ore_rewards_factor;
+        member.shares = 0;
+        member.rewards_sol = 0;
+        member.rewards_ore = 0;
+        member.lifetime_rewards_sol = 0;
+        member.lifetime_rewards_ore = 0;
+        member
+    } else {
+        member_info
+            .as_account_mut::<PoolMember>(&ore_api::ID)?
+            .assert_mut(|m| m.authority == *signer_info.key)?
+    };
+
+    // Checkpoint rewards before shares change.
+    member.update_rewards(pool);
+
+    // Deposit SOL into the pool.
+    let shares = pool.deposit(amount);
+    if shares == 0 {
+        return Err(OreError::AmountTooSmall.into());
+    }
+    member.shares += shares;
+    pool_info.collect(amount, signer_info)?;
+
+    sol_log(
+        &format!(
+            "Depositing {} SOL for {} shares",
+            lamports_to_sol(amount),
+            shares
+        )
+        .as_str(),
+    );
+
+    Ok(())
+}
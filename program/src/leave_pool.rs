@@ -0,0 +1,31 @@
+use ore_api::prelude::*;
+use solana_program::{log::sol_log, native_token::lamports_to_sol};
+use steel::*;
+
+/// Redeems a member's pool shares for their share of the pool balance.
+/// While capital is deployed, the member keeps the shares backing it until it is harvested.
+/// Rewards earned up to this point remain claimable.
+pub fn process_leave_pool(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, member_info, pool_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?.is_writable()?;
+    let member = member_info
+        .as_account_mut::<PoolMember>(&ore_api::ID)?
+        .assert_mut(|m| m.authority == *signer_info.key)?
+        .assert_mut(|m| m.pool == *pool_info.key)?;
+    let pool = pool_info.as_account_mut::<Pool>(&ore_api::ID)?;
+
+    // Checkpoint rewards before shares change.
+    member.update_rewards(pool);
+
+    // Withdraw SOL from the pool.
+    let (shares, amount) = pool.withdraw(member.shares);
+    member.shares -= shares;
+    pool_info.send(amount, signer_info);
+
+    sol_log(&format!("Withdrawing {} SOL", lamports_to_sol(amount)).as_str());
+
+    Ok(())
+}
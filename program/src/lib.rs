@@ -1,35 +1,123 @@
+mod add_to_allowlist;
+mod allowlist;
 mod automate;
 mod bury;
 mod checkpoint;
 mod claim_ore;
+mod claim_ore_to_stake;
+mod claim_pool;
+mod claim_referral;
+mod claim_season;
 mod claim_seeker;
 mod claim_sol;
 mod claim_yield;
+mod claim_yield_sol;
 mod close;
+mod close_miner;
+mod close_stake;
+mod commit;
+mod compound_yield;
+mod create_pool;
+mod create_season;
 mod deploy;
 mod deposit;
+mod distribute_fees;
+mod fund_season;
+mod harvest_pool;
+mod join_pool;
+mod leave_pool;
 mod log;
+mod migrate;
+mod migrate_staker;
+mod refresh_stake;
+mod remove_collection;
+mod remove_from_allowlist;
 mod reset;
 mod set_admin;
+mod set_allowlist_enabled;
+mod set_auto_compound;
+mod set_board_size;
+mod set_claim_delegate;
+mod set_close_destination;
+mod set_collection;
+mod set_crank_bounty;
+mod set_deploy_limits;
+mod set_entropy_source;
 mod set_fee_collector;
-mod whitelist;
+mod set_fee_split;
+mod set_referral_fee;
+mod set_seeker_activation;
+mod set_seeker_multiplier;
+mod set_stake_claim_discount;
+mod set_stake_sol_yield;
+mod set_unstake_cooldown;
+mod set_winning_squares;
+mod set_wrap_policy;
+mod sponsor;
+mod transfer_miner;
+mod transfer_stake;
+mod unstake;
 mod withdraw;
 mod wrap;
 
+use add_to_allowlist::*;
 use automate::*;
 use bury::*;
 use checkpoint::*;
 use claim_ore::*;
+use claim_ore_to_stake::*;
+use claim_pool::*;
+use claim_referral::*;
+use claim_season::*;
 use claim_seeker::*;
 use claim_sol::*;
 use claim_yield::*;
+use claim_yield_sol::*;
 use close::*;
+use close_miner::*;
+use close_stake::*;
+use commit::*;
+use compound_yield::*;
+use create_pool::*;
+use create_season::*;
 use deploy::*;
 use deposit::*;
+use distribute_fees::*;
+use fund_season::*;
+use harvest_pool::*;
+use join_pool::*;
+use leave_pool::*;
 use log::*;
+use migrate::*;
+use migrate_staker::*;
+use refresh_stake::*;
+use remove_collection::*;
+use remove_from_allowlist::*;
 use reset::*;
 use set_admin::*;
+use set_allowlist_enabled::*;
+use set_auto_compound::*;
+use set_board_size::*;
+use set_claim_delegate::*;
+use set_close_destination::*;
+use set_collection::*;
+use set_crank_bounty::*;
+use set_deploy_limits::*;
+use set_entropy_source::*;
 use set_fee_collector::*;
+use set_fee_split::*;
+use set_referral_fee::*;
+use set_seeker_activation::*;
+use set_seeker_multiplier::*;
+use set_stake_claim_discount::*;
+use set_stake_sol_yield::*;
+use set_unstake_cooldown::*;
+use set_winning_squares::*;
+use set_wrap_policy::*;
+use sponsor::*;
+use transfer_miner::*;
+use transfer_stake::*;
+use unstake::*;
 use withdraw::*;
 use wrap::*;
 
@@ -49,24 +137,76 @@ pub fn process_instruction(
         OreInstruction::Checkpoint => process_checkpoint(accounts, data)?,
         OreInstruction::ClaimSOL => process_claim_sol(accounts, data)?,
         OreInstruction::ClaimORE => process_claim_ore(accounts, data)?,
+        OreInstruction::ClaimOreToStake => process_claim_ore_to_stake(accounts, data)?,
+        OreInstruction::TransferMiner => process_transfer_miner(accounts, data)?,
+        OreInstruction::CloseMiner => process_close_miner(accounts, data)?,
+        OreInstruction::SetClaimDelegate => process_set_claim_delegate(accounts, data)?,
+        OreInstruction::ClaimReferral => process_claim_referral(accounts, data)?,
         OreInstruction::Deploy => process_deploy(accounts, data)?,
         OreInstruction::Log => process_log(accounts, data)?,
         OreInstruction::Close => process_close(accounts, data)?,
         OreInstruction::Reset => process_reset(accounts, data)?,
+        OreInstruction::Sponsor => process_sponsor(accounts, data)?,
+        OreInstruction::DistributeFees => process_distribute_fees(accounts, data)?,
 
         // Staker
         OreInstruction::Deposit => process_deposit(accounts, data)?,
+        OreInstruction::Unstake => process_unstake(accounts, data)?,
         OreInstruction::Withdraw => process_withdraw(accounts, data)?,
         OreInstruction::ClaimYield => process_claim_yield(accounts, data)?,
+        OreInstruction::ClaimYieldSol => process_claim_yield_sol(accounts, data)?,
+        OreInstruction::CompoundYield => process_compound_yield(accounts, data)?,
+        OreInstruction::SetAutoCompound => process_set_auto_compound(accounts, data)?,
+        OreInstruction::TransferStake => process_transfer_stake(accounts, data)?,
+        OreInstruction::CloseStake => process_close_stake(accounts, data)?,
+        OreInstruction::RefreshStake => process_refresh_stake(accounts, data)?,
 
         // Admin
         OreInstruction::Bury => process_bury(accounts, data)?,
         OreInstruction::Wrap => process_wrap(accounts, data)?,
         OreInstruction::SetAdmin => process_set_admin(accounts, data)?,
         OreInstruction::SetFeeCollector => process_set_fee_collector(accounts, data)?,
+        OreInstruction::SetEntropySource => process_set_entropy_source(accounts, data)?,
+        OreInstruction::SetCrankBounty => process_set_crank_bounty(accounts, data)?,
+        OreInstruction::SetCloseDestination => process_set_close_destination(accounts, data)?,
+        OreInstruction::SetStakeSolYield => process_set_stake_sol_yield(accounts, data)?,
+        OreInstruction::SetSeekerMultiplier => process_set_seeker_multiplier(accounts, data)?,
+        OreInstruction::SetUnstakeCooldown => process_set_unstake_cooldown(accounts, data)?,
+        OreInstruction::SetStakeClaimDiscount => process_set_stake_claim_discount(accounts, data)?,
+        OreInstruction::SetReferralFee => process_set_referral_fee(accounts, data)?,
+        OreInstruction::SetDeployLimits => process_set_deploy_limits(accounts, data)?,
+        OreInstruction::AddToAllowlist => process_add_to_allowlist(accounts, data)?,
+        OreInstruction::RemoveFromAllowlist => process_remove_from_allowlist(accounts, data)?,
+        OreInstruction::SetAllowlistEnabled => process_set_allowlist_enabled(accounts, data)?,
+        OreInstruction::SetCollection => process_set_collection(accounts, data)?,
+        OreInstruction::RemoveCollection => process_remove_collection(accounts, data)?,
+        OreInstruction::SetSeekerActivation => process_set_seeker_activation(accounts, data)?,
+        OreInstruction::SetWinningSquares => process_set_winning_squares(accounts, data)?,
+        OreInstruction::SetBoardSize => process_set_board_size(accounts, data)?,
+        OreInstruction::SetFeeSplit => process_set_fee_split(accounts, data)?,
+        OreInstruction::SetWrapPolicy => process_set_wrap_policy(accounts, data)?,
 
         // Seeker
         OreInstruction::ClaimSeeker => process_claim_seeker(accounts, data)?,
+
+        // Entropy
+        OreInstruction::Commit => process_commit(accounts, data)?,
+
+        // Pool
+        OreInstruction::CreatePool => process_create_pool(accounts, data)?,
+        OreInstruction::JoinPool => process_join_pool(accounts, data)?,
+        OreInstruction::LeavePool => process_leave_pool(accounts, data)?,
+        OreInstruction::ClaimPool => process_claim_pool(accounts, data)?,
+        OreInstruction::HarvestPool => process_harvest_pool(accounts, data)?,
+
+        // Season
+        OreInstruction::CreateSeason => process_create_season(accounts, data)?,
+        OreInstruction::FundSeason => process_fund_season(accounts, data)?,
+        OreInstruction::ClaimSeason => process_claim_season(accounts, data)?,
+
+        // Migration
+        OreInstruction::Migrate => process_migrate(accounts, data)?,
+        OreInstruction::MigrateStaker => process_migrate_staker(accounts, data)?,
         _ => return Err(ProgramError::InvalidInstructionData),
     }
 
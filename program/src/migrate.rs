@@ -0,0 +1,74 @@
+use ore_api::prelude::*;
+use solana_program::rent::Rent;
+use steel::*;
+
+/// Migrates a program account to the current layout.
+pub fn process_migrate(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, account_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    account_info.is_writable()?.has_owner(&ore_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Migrate the account by its type.
+    let discriminator = account_info.try_borrow_data()?[0];
+    match OreAccount::try_from(discriminator).map_err(|_| ProgramError::InvalidAccountData)? {
+        OreAccount::Allowlist => migrate_account::<Allowlist>(account_info, signer_info)?,
+        OreAccount::Automation => migrate_account::<Automation>(account_info, signer_info)?,
+        OreAccount::Board => migrate_account::<Board>(account_info, signer_info)?,
+        OreAccount::Bonus => migrate_account::<Bonus>(account_info, signer_info)?,
+        OreAccount::Collection => migrate_account::<Collection>(account_info, signer_info)?,
+        OreAccount::Config => migrate_account::<Config>(account_info, signer_info)?,
+        OreAccount::FeeSplit => migrate_account::<FeeSplit>(account_info, signer_info)?,
+        OreAccount::History => migrate_account::<History>(account_info, signer_info)?,
+        OreAccount::Miner => migrate_account::<Miner>(account_info, signer_info)?,
+        OreAccount::Pool => migrate_account::<Pool>(account_info, signer_info)?,
+        OreAccount::PoolMember => migrate_account::<PoolMember>(account_info, signer_info)?,
+        OreAccount::Referrer => migrate_account::<Referrer>(account_info, signer_info)?,
+        OreAccount::Round => migrate_account::<Round>(account_info, signer_info)?,
+        OreAccount::Season => migrate_account::<Season>(account_info, signer_info)?,
+        OreAccount::SeasonMiner => migrate_account::<SeasonMiner>(account_info, signer_info)?,
+        OreAccount::Seeker => migrate_account::<Seeker>(account_info, signer_info)?,
+        OreAccount::Stake => migrate_account::<Stake>(account_info, signer_info)?,
+        OreAccount::Treasury => migrate_account::<Treasury>(account_info, signer_info)?,
+    }
+
+    Ok(())
+}
+
+/// Resizes an account to the current layout of `T` and defaults any appended fields. The payer
+/// funds the additional rent. Accounts already in the current layout are left untouched.
+pub fn migrate_account<'a, T: Migratable>(
+    account_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    // Check the account type.
+    account_info.has_owner(&ore_api::ID)?;
+    if account_info.try_borrow_data()?.first() != Some(&T::discriminator()) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Check if the account is already in the current layout.
+    let prior_len = account_info.data_len();
+    if prior_len >= T::SIZE {
+        return Ok(());
+    }
+
+    // Fund the rent for the appended bytes.
+    let rent = Rent::get()?;
+    let additional_rent = rent
+        .minimum_balance(T::SIZE)
+        .saturating_sub(rent.minimum_balance(prior_len));
+    if additional_rent > 0 {
+        account_info.collect(additional_rent, payer_info)?;
+    }
+
+    // Resize the account and default appended fields.
+    account_info.resize(T::SIZE)?;
+    let account = account_info.as_account_mut::<T>(&ore_api::ID)?;
+    account.migrate(prior_len);
+
+    Ok(())
+}
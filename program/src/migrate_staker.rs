@@ -1,7 +1,10 @@
 use ore_api::prelude::*;
 use steel::*;
 
-/// Sets the admin.
+use crate::migrate_account;
+
+/// Migrates a legacy stake account to the current layout, and moves its staked ORE out of the
+/// treasury into the stake account's own token account.
 pub fn process_migrate_staker(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let [signer_info, config_info, mint_info, stake_info, stake_tokens_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
@@ -15,14 +18,22 @@ pub fn process_migrate_staker(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Pro
         OreError::NotAuthorized.into(),
     )?;
     mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
-    let stake = stake_info.as_account_mut::<Stake>(&ore_api::ID)?;
+    stake_info.is_writable()?.has_owner(&ore_api::ID)?;
     stake_tokens_info.is_empty()?.is_writable()?;
-    treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+    treasury_info
+        .is_writable()?
+        .has_address(&TREASURY_ADDRESS)?;
     treasury_tokens_info.as_associated_token_account(&treasury_info.key, &MINT_ADDRESS)?;
     system_program.is_program(&system_program::ID)?;
     token_program.is_program(&spl_token::ID)?;
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
 
+    // Migrate the stake and treasury accounts to the current layout.
+    migrate_account::<Treasury>(treasury_info, signer_info)?;
+    migrate_account::<Stake>(stake_info, signer_info)?;
+    treasury_info.as_account::<Treasury>(&ore_api::ID)?;
+    let stake = stake_info.as_account::<Stake>(&ore_api::ID)?;
+
     // Create stake tokens account.
     if stake_tokens_info.data_is_empty() {
         create_associated_token_account(
@@ -0,0 +1,20 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Drops an expired lock and recalculates the reward weight of a stake account.
+pub fn process_refresh_stake(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, stake_info, treasury_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    let stake = stake_info.as_account_mut::<Stake>(&ore_api::ID)?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+
+    // Refresh the stake weight.
+    stake.refresh(&clock, config, treasury);
+
+    Ok(())
+}
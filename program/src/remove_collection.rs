@@ -0,0 +1,29 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Removes a collection from the registry. Perks already claimed are kept.
+pub fn process_remove_collection(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = RemoveCollection::try_from_bytes(data)?;
+    let mint_authority = Pubkey::new_from_array(args.mint_authority);
+
+    // Load accounts.
+    let [signer_info, collection_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    collection_info
+        .is_writable()?
+        .as_account::<Collection>(&ore_api::ID)?
+        .assert(|c| c.mint_authority == mint_authority)?;
+    config_info.as_account::<Config>(&ore_api::ID)?.assert_err(
+        |c| c.admin == *signer_info.key,
+        OreError::NotAuthorized.into(),
+    )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Close collection account, returning rent to the admin.
+    collection_info.close(signer_info)?;
+
+    Ok(())
+}
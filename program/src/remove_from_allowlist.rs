@@ -0,0 +1,29 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Removes an authority from the allowlist.
+pub fn process_remove_from_allowlist(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = RemoveFromAllowlist::try_from_bytes(data)?;
+    let authority = Pubkey::new_from_array(args.authority);
+
+    // Load accounts.
+    let [signer_info, allowlist_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    allowlist_info
+        .is_writable()?
+        .as_account::<Allowlist>(&ore_api::ID)?
+        .assert(|a| a.authority == authority)?;
+    config_info.as_account::<Config>(&ore_api::ID)?.assert_err(
+        |c| c.admin == *signer_info.key,
+        OreError::NotAuthorized.into(),
+    )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Close allowlist entry, returning rent to the admin.
+    allowlist_info.close(signer_info)?;
+
+    Ok(())
+}
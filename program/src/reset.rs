@@ -1,24 +1,39 @@
 use ore_api::prelude::*;
-use solana_program::slot_hashes::SlotHashes;
+use solana_program::{hash::Hash, slot_hashes::SlotHashes};
 use steel::*;
 
+use crate::migrate_account;
+
 /// Pays out the winners and block reward.
-pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+pub fn process_reset(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Reset::try_from_bytes(data)?;
+
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, board_info, config_info, fee_collector_info, mint_info, round_info, round_next_info, _top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, ore_program, slot_hashes_sysvar] =
+    let [signer_info, board_info, bonus_info, config_info, fee_collector_info, history_info, mint_info, round_info, round_next_info, season_info, sponsor_info, _top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, ore_program, slot_hashes_sysvar] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
+
+    // Migrate a round created before the board size was configurable.
+    migrate_account::<Round>(round_info, signer_info)?;
+
     let board = board_info
         .as_account_mut::<Board>(&ore_api::ID)?
         .assert_mut(|b| clock.slot >= b.end_slot + INTERMISSION_SLOTS)?;
+    bonus_info
+        .is_writable()?
+        .has_seeds(&[BONUS, &board.round_id.to_le_bytes()], &ore_api::ID)?;
     let config = config_info.as_account::<Config>(&ore_api::ID)?;
     fee_collector_info
         .is_writable()?
         .has_address(&config.fee_collector)?;
+    history_info
+        .is_writable()?
+        .has_seeds(&[HISTORY], &ore_api::ID)?;
     let round = round_info
         .as_account_mut::<Round>(&ore_api::ID)?
         .assert_mut(|r| r.id == board.round_id)?;
@@ -26,6 +41,9 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
         .is_empty()?
         .is_writable()?
         .has_seeds(&[ROUND, &(board.round_id + 1).to_le_bytes()], &ore_api::ID)?;
+    season_info
+        .is_writable()?
+        .has_seeds(&[SEASON, &config.season_id.to_le_bytes()], &ore_api::ID)?;
     let mint = mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
     treasury_tokens_info.as_associated_token_account(&treasury_info.key, &mint_info.key)?;
@@ -44,9 +62,9 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     )?;
     let round_next = round_next_info.as_account_mut::<Round>(&ore_api::ID)?;
     round_next.id = board.round_id + 1;
-    round_next.deployed = [0; 25];
+    round_next.deployed = [0; MAX_SQUARES];
     round_next.slot_hash = [0; 32];
-    round_next.count = [0; 25];
+    round_next.count = [0; MAX_SQUARES];
     round_next.expires_at = u64::MAX; // Set to max, to indicate round is waiting for first deploy to begin.
     round_next.rent_payer = *signer_info.key;
     round_next.motherlode = 0;
@@ -55,21 +73,110 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     round_next.total_deployed = 0;
     round_next.total_vaulted = 0;
     round_next.total_winnings = 0;
+    round_next.sample_slot = 0;
+    round_next.entropy_source = config.entropy_source;
+    round_next.entropy_commit = [0; 32];
+    round_next.entropy_reveal = [0; 32];
+    round_next.motherlode_sol = 0;
+    round_next.total_referred = 0;
+    round_next.referral_fee = 0;
+    round_next.bonus_sol = 0;
+    round_next.bonus_ore = 0;
+    round_next.season_id = 0;
+    round_next.winning_squares = config.winning_squares.max(1);
+    round_next.squares = board.num_squares() as u64;
+    round_next.unclaimed_bonus_ore = 0;
+
+    // Assign the next round to the current season, and open season claims once the last
+    // round of the season expires.
+    if !season_info.data_is_empty() {
+        let season = season_info.as_account_mut::<Season>(&ore_api::ID)?;
+        if season.contains(round_next.id) {
+            round_next.season_id = season.id;
+        }
+        if round.id == season.end_round {
+            season.claimable_at = round.expires_at;
+        }
+    }
+
+    // Collect the sponsored bonus for this round.
+    // The escrow's rent is returned to the sponsor who opened it.
+    if !bonus_info.data_is_empty() {
+        let bonus = bonus_info.as_account::<Bonus>(&ore_api::ID)?;
+        sponsor_info.is_writable()?.has_address(&bonus.sponsor)?;
+        round.bonus_sol += bonus.amount_sol;
+        round.bonus_ore += bonus.amount_ore;
+        bonus_info.send(bonus.amount_sol, round_info);
+        bonus_info.close(sponsor_info)?;
+    }
+
+    // Pay crank bounty.
+    let bounty = config.reset_bounty.min(treasury.balance);
+    if bounty > 0 {
+        treasury.balance -= bounty;
+        treasury_info.send(bounty, signer_info);
+        program_log(
+            &[board_info.clone(), ore_program.clone()],
+            BountyEvent {
+                disc: 3,
+                round_id: round.id,
+                kind: BountyKind::Reset as u64,
+                authority: *signer_info.key,
+                amount: bounty,
+                ts: clock.unix_timestamp,
+            }
+            .to_bytes(),
+        )?;
+    }
 
     // Sample slot hash.
-    if let Ok(slot_hash) = get_slot_hash(board.end_slot, slot_hashes_sysvar) {
-        round.slot_hash = slot_hash;
+    // If the end slot hash has expired, fall back to the earliest slot hash still available.
+    let slot_hash = if let Ok((slot, slot_hash)) = get_slot_hash(board.end_slot, slot_hashes_sysvar)
+    {
+        round.sample_slot = slot;
+        Some(slot_hash)
     } else {
-        round.slot_hash = [u8::MAX; 32];
-    }
+        round.sample_slot = 0;
+        None
+    };
+
+    // Derive the seed from the round's entropy source.
+    // If the randomness authority never committed, fall back to the slot hash.
+    let seed = match round.entropy_source() {
+        EntropySource::CommitReveal if round.entropy_commit != [0; 32] => {
+            let entropy = CommitRevealEntropy {
+                commit: round.entropy_commit,
+                reveal: args.reveal,
+            };
+            if entropy.is_valid() {
+                round.entropy_reveal = args.reveal;
+                slot_hash.and_then(|h| entropy.seed(&h))
+            } else if clock.slot < board.end_slot + REVEAL_WINDOW_SLOTS {
+                // Wait for the randomness authority to reveal.
+                return Err(trace("Invalid reveal", OreError::InvalidReveal.into()));
+            } else {
+                // The randomness authority missed the reveal window, so the round is refunded.
+                // Falling back to the slot hash would let it pick an outcome by withholding the reveal.
+                None
+            }
+        }
+        _ => slot_hash.and_then(|h| SlotHashEntropy.seed(&h)),
+    };
+    round.slot_hash = seed.unwrap_or([u8::MAX; 32]);
 
-    // Exit early if no slot hash was found.
+    // Exit early if no seed was derived.
     let Some(r) = round.rng() else {
-        // Slot hash could not be found, refund all SOL.
+        // Slot hash could not be found or the reveal was missed, refund all SOL.
         round.total_vaulted = 0;
         round.total_winnings = 0;
         round.total_deployed = 0;
 
+        // Roll the sponsored bonus into the next round.
+        roll_over_bonus(round, round_next, round_info, round_next_info);
+
+        // Record the round in the history.
+        record_history(history_info, round, 0)?;
+
         // Emit event.
         program_log(
             &[board_info.clone(), ore_program.clone()],
@@ -87,6 +194,9 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
                 total_winnings: round.total_winnings,
                 total_minted: 0,
                 ts: clock.unix_timestamp,
+                bonus_sol: round.bonus_sol,
+                bonus_ore: round.bonus_ore,
+                winning_mask: 0,
             }
             .to_bytes(),
         )?;
@@ -100,15 +210,33 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
 
     // Caculate admin fees.
     let total_admin_fee = round.total_deployed / 100;
+    let stake_admin_fee = config.stake_admin_fee_share(total_admin_fee);
+
+    // Reserve a share of the admin fee on referred deployments for referrers, paid out at checkpoint.
+    let referral_fee = config
+        .referral_fee_share(round.total_referred / 100)
+        .min(total_admin_fee - stake_admin_fee);
+    round.referral_fee = referral_fee;
 
-    // Get the winning square.
+    // Get the winning squares.
     let winning_square = round.winning_square(r);
+    let winning_mask = round.winning_mask(r);
 
-    // If no one deployed on the winning square, vault all deployed.
-    if round.deployed[winning_square] == 0 {
+    // If no one deployed on a winning square, vault all deployed.
+    if round.winning_deployed(winning_mask) == 0 {
         // Vault all deployed.
         round.total_vaulted = round.total_deployed - total_admin_fee;
-        treasury.balance += round.total_deployed - total_admin_fee;
+        let stake_vault = config.stake_vault_share(round.total_vaulted);
+        treasury.balance += round.total_vaulted - stake_vault;
+
+        // Share SOL yield with stakers.
+        treasury.share_sol_with_stakers(stake_admin_fee + stake_vault);
+
+        // Roll the sponsored bonus into the next round.
+        roll_over_bonus(round, round_next, round_info, round_next_info);
+
+        // Record the round in the history.
+        record_history(history_info, round, winning_mask)?;
 
         // Emit event.
         program_log(
@@ -127,6 +255,9 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
                 total_winnings: round.total_winnings,
                 total_minted: 0,
                 ts: clock.unix_timestamp,
+                bonus_sol: round.bonus_sol,
+                bonus_ore: round.bonus_ore,
+                winning_mask,
             }
             .to_bytes(),
         )?;
@@ -137,13 +268,20 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
         board.end_slot = u64::MAX;
 
         // Do SOL transfers.
-        round_info.send(total_admin_fee, &fee_collector_info);
-        round_info.send(round.total_deployed - total_admin_fee, &treasury_info);
+        pay_admin_fee(
+            round_info,
+            fee_collector_info,
+            total_admin_fee - stake_admin_fee - referral_fee,
+        )?;
+        round_info.send(
+            round.total_deployed - total_admin_fee + stake_admin_fee,
+            &treasury_info,
+        );
         return Ok(());
     }
 
     // Get winnings amount (total deployed on all non-winning squares, minus admin fee).
-    let winnings = round.calculate_total_winnings(winning_square);
+    let winnings = round.calculate_total_winnings(winning_mask);
     let winnings_admin_fee = winnings / 100; // 1% admin fee.
     let winnings = winnings - winnings_admin_fee;
 
@@ -152,14 +290,21 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     let winnings = winnings - vault_amount;
     round.total_winnings = winnings;
     round.total_vaulted = vault_amount;
-    treasury.balance += vault_amount;
+    let stake_vault = config.stake_vault_share(vault_amount);
+    treasury.balance += vault_amount - stake_vault;
+
+    // Share SOL yield with stakers.
+    treasury.share_sol_with_stakers(stake_admin_fee + stake_vault);
+
+    // Track the sponsored ORE owed to winning miners until they checkpoint.
+    round.unclaimed_bonus_ore = round.bonus_ore;
 
     // Sanity check.
     assert!(
         round.total_deployed
             >= round.total_vaulted
                 + round.total_winnings
-                + round.deployed[winning_square]
+                + round.winning_deployed(winning_mask)
                 + winnings_admin_fee
     );
 
@@ -183,7 +328,9 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     // Payout the motherlode if it was activated.
     if round.did_hit_motherlode(r) {
         round.motherlode = treasury.motherlode;
+        round.motherlode_sol = treasury.motherlode_sol;
         treasury.motherlode = 0;
+        treasury.motherlode_sol = 0;
     }
 
     // Mint +0.2 ORE to the motherlode rewards pool.
@@ -213,6 +360,9 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     //             && top_miner_sample < m.cumulative[winning_square] + m.deployed[winning_square]
     //     })?;
 
+    // Record the round in the history.
+    record_history(history_info, round, winning_mask)?;
+
     // Emit event.
     program_log(
         &[board_info.clone(), ore_program.clone()],
@@ -224,12 +374,15 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
             winning_square: winning_square as u64,
             top_miner: round.top_miner,
             motherlode: round.motherlode,
-            num_winners: round.count[winning_square],
+            num_winners: round.winning_count(winning_mask),
             total_deployed: round.total_deployed,
             total_vaulted: round.total_vaulted,
             total_winnings: round.total_winnings,
             total_minted: mint_amount + motherlode_mint_amount,
             ts: clock.unix_timestamp,
+            bonus_sol: round.bonus_sol,
+            bonus_ore: round.bonus_ore,
+            winning_mask,
         }
         .to_bytes(),
     )?;
@@ -240,26 +393,142 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     board.end_slot = u64::MAX; // board.start_slot + 150;
 
     // Do SOL transfers.
-    round_info.send(total_admin_fee, &fee_collector_info);
-    round_info.send(vault_amount, &treasury_info);
+    pay_admin_fee(
+        round_info,
+        fee_collector_info,
+        total_admin_fee - stake_admin_fee - referral_fee,
+    )?;
+    round_info.send(vault_amount + stake_admin_fee, &treasury_info);
+    if round.motherlode_sol > 0 {
+        treasury_info.send(round.motherlode_sol, &round_info);
+    }
 
     Ok(())
 }
 
+/// Rolls a round's sponsored bonus into the next round, when no one won it.
+fn roll_over_bonus(
+    round: &Round,
+    round_next: &mut Round,
+    round_info: &AccountInfo<'_>,
+    round_next_info: &AccountInfo<'_>,
+) {
+    round_next.bonus_sol += round.bonus_sol;
+    round_next.bonus_ore += round.bonus_ore;
+    if round.bonus_sol > 0 {
+        round_info.send(round.bonus_sol, round_next_info);
+    }
+}
+
+/// Pays the admin fee to the fee collector. If the fee collector is the fee split, the fee
+/// accrues to its balance until it is distributed.
+fn pay_admin_fee(
+    round_info: &AccountInfo<'_>,
+    fee_collector_info: &AccountInfo<'_>,
+    amount: u64,
+) -> ProgramResult {
+    round_info.send(amount, fee_collector_info);
+    if fee_collector_info.owner == &ore_api::ID
+        && fee_collector_info.try_borrow_data()?.first() == Some(&FeeSplit::discriminator())
+    {
+        let fee_split = fee_collector_info.as_account_mut::<FeeSplit>(&ore_api::ID)?;
+        fee_split.balance += amount;
+    }
+    Ok(())
+}
+
+/// Records a summary of the round in the history account, if it has been created.
+/// The top miner is recorded when they checkpoint.
+fn record_history(
+    history_info: &AccountInfo<'_>,
+    round: &Round,
+    winning_mask: u64,
+) -> ProgramResult {
+    if history_info.data_is_empty() {
+        return Ok(());
+    }
+    let history = history_info.as_account_mut::<History>(&ore_api::ID)?;
+    history.push(RoundSummary {
+        round_id: round.id,
+        winning_mask,
+        total_deployed: round.total_deployed,
+        total_winnings: round.total_winnings,
+        top_miner: Pubkey::default(),
+        motherlode: round.motherlode,
+        is_split: (round.top_miner == SPLIT_ADDRESS) as u64,
+    });
+    Ok(())
+}
+
 pub fn get_slot_hash(
     slot: u64,
     slot_hashes_sysvar: &AccountInfo<'_>,
-) -> Result<[u8; 32], ProgramError> {
+) -> Result<(u64, [u8; 32]), ProgramError> {
     let slot_hashes =
         bincode::deserialize::<SlotHashes>(slot_hashes_sysvar.data.borrow().as_ref()).unwrap();
-    let Some(slot_hash) = slot_hashes.get(&slot) else {
-        // If reset is not called within ~2.5 minutes of the block ending,
+    let Some((slot, slot_hash)) = find_slot_hash(&slot_hashes, slot) else {
+        // If reset is not called within ~2.5 minutes of the fallback window closing,
         // then the slot hash will be unavailable and secure hashes cannot be generated.
         return Err(trace(
             "Slot hash unavailable",
             ProgramError::InvalidAccountData,
         ));
     };
-    let slot_hash = slot_hash.to_bytes();
-    Ok(slot_hash)
+    Ok((slot, slot_hash.to_bytes()))
+}
+
+/// Returns the earliest slot hash available in the range [slot, slot + SLOT_HASH_FALLBACK_SLOTS].
+///
+/// Note the fallback is not neutral: once the end slot hash has expired, each later slot in the
+/// window expires in turn, so the reset cranker can choose among the hashes in the window by
+/// timing the reset.
+pub fn find_slot_hash(slot_hashes: &SlotHashes, slot: u64) -> Option<(u64, Hash)> {
+    if let Some(slot_hash) = slot_hashes.get(&slot) {
+        return Some((slot, *slot_hash));
+    }
+
+    // Slot hashes are sorted in descending order, so iterate in reverse to find the earliest match.
+    let max_slot = slot.saturating_add(SLOT_HASH_FALLBACK_SLOTS);
+    slot_hashes
+        .iter()
+        .rev()
+        .find(|(s, _)| *s > slot && *s <= max_slot)
+        .map(|(s, h)| (*s, *h))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot_hashes(slots: &[u64]) -> SlotHashes {
+        let entries: Vec<(u64, Hash)> = slots
+            .iter()
+            .map(|s| (*s, Hash::new_from_array([*s as u8; 32])))
+            .collect();
+        SlotHashes::new(&entries)
+    }
+
+    #[test]
+    fn test_find_slot_hash_exact() {
+        let slot_hashes = slot_hashes(&[98, 99, 100, 101, 102]);
+        let (slot, hash) = find_slot_hash(&slot_hashes, 100).unwrap();
+        assert_eq!(slot, 100);
+        assert_eq!(hash, Hash::new_from_array([100; 32]));
+    }
+
+    #[test]
+    fn test_find_slot_hash_fallback() {
+        // The end slot has expired, so the earliest later slot in the window is used.
+        let slot_hashes = slot_hashes(&[103, 105, 110]);
+        let (slot, hash) = find_slot_hash(&slot_hashes, 100).unwrap();
+        assert_eq!(slot, 103);
+        assert_eq!(hash, Hash::new_from_array([103; 32]));
+    }
+
+    #[test]
+    fn test_find_slot_hash_miss() {
+        // Only slots before the end slot or past the fallback window are available.
+        let slot_hashes = slot_hashes(&[90, 99, 100 + SLOT_HASH_FALLBACK_SLOTS + 1]);
+        assert!(find_slot_hash(&slot_hashes, 100).is_none());
+    }
 }
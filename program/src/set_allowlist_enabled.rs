@@ -0,0 +1,27 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Enables or disables the allowlist.
+pub fn process_set_allowlist_enabled(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetAllowlistEnabled::try_from_bytes(data)?;
+    let enabled = u64::from_le_bytes(args.enabled);
+
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Set allowlist flag.
+    config.allowlist_enabled = (enabled > 0) as u64;
+
+    Ok(())
+}
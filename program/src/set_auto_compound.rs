@@ -0,0 +1,23 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Opts a stake account in or out of permissionless yield compounding.
+pub fn process_set_auto_compound(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetAutoCompound::try_from_bytes(data)?;
+    let auto_compound = u64::from_le_bytes(args.auto_compound);
+
+    // Load accounts.
+    let [signer_info, stake_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let stake = stake_info
+        .as_account_mut::<Stake>(&ore_api::ID)?
+        .assert_mut(|s| s.authority == *signer_info.key)?;
+
+    // Set auto compound flag.
+    stake.auto_compound = (auto_compound > 0) as u64;
+
+    Ok(())
+}
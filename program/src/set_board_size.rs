@@ -0,0 +1,31 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the number of squares on the board for each new round.
+pub fn process_set_board_size(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetBoardSize::try_from_bytes(data)?;
+    let squares = u64::from_le_bytes(args.squares);
+
+    // Load accounts.
+    let [signer_info, board_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let board = board_info.as_account_mut::<Board>(&ore_api::ID)?;
+    config_info.as_account::<Config>(&ore_api::ID)?.assert_err(
+        |c| c.admin == *signer_info.key,
+        OreError::NotAuthorized.into(),
+    )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate number of squares. At least one square must lose each round.
+    if squares < 2 || squares > MAX_SQUARES as u64 {
+        return Err(OreError::InvalidBoardSize.into());
+    }
+
+    // Set number of squares. Rounds already opened are not changed.
+    board.squares = squares;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the claim delegate and payout recipient of a miner account.
+pub fn process_set_claim_delegate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetClaimDelegate::try_from_bytes(data)?;
+
+    // Load accounts.
+    let [signer_info, miner_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let miner = miner_info
+        .as_account_mut::<Miner>(&ore_api::ID)?
+        .assert_mut(|m| m.authority == *signer_info.key)?;
+
+    // Set claim delegate and payout recipient.
+    miner.claim_delegate = Pubkey::new_from_array(args.claim_delegate);
+    miner.payout_recipient = Pubkey::new_from_array(args.payout_recipient);
+
+    Ok(())
+}
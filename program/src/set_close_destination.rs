@@ -0,0 +1,27 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the destination of unclaimed SOL from expired rounds.
+pub fn process_set_close_destination(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetCloseDestination::try_from_bytes(data)?;
+    let close_destination = CloseDestination::from_u64(u64::from_le_bytes(args.close_destination))?;
+
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Set close destination.
+    config.close_destination = close_destination as u64;
+
+    Ok(())
+}
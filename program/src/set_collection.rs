@@ -0,0 +1,71 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Registers or updates a collection whose tokens can be claimed for a perk.
+pub fn process_set_collection(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetCollection::try_from_bytes(data)?;
+    let mint_authority = Pubkey::new_from_array(args.mint_authority);
+    let metadata_address = Pubkey::new_from_array(args.metadata_address);
+    let token_program = Pubkey::new_from_array(args.token_program);
+    let perk = u64::from_le_bytes(args.perk);
+    let perk_bps = u64::from_le_bytes(args.perk_bps);
+
+    // Load accounts.
+    let [signer_info, collection_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    collection_info
+        .is_writable()?
+        .has_seeds(&[COLLECTION, &mint_authority.to_bytes()], &ore_api::ID)?;
+    config_info.as_account::<Config>(&ore_api::ID)?.assert_err(
+        |c| c.admin == *signer_info.key,
+        OreError::NotAuthorized.into(),
+    )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate token program.
+    if token_program != spl_token::ID && token_program != spl_token_2022::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Validate perk.
+    if perk > u8::MAX as u64 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let max_perk_bps = match CollectionPerk::try_from(perk as u8)
+        .map_err(|_| ProgramError::InvalidInstructionData)?
+    {
+        CollectionPerk::StakeMultiplier => MAX_SEEKER_MULTIPLIER_BPS,
+        CollectionPerk::ClaimFeeDiscount => DENOMINATOR_BPS,
+    };
+    if perk_bps == 0 || perk_bps > max_perk_bps {
+        return Err(OreError::InvalidBps.into());
+    }
+
+    // Open collection account.
+    let collection = if collection_info.data_is_empty() {
+        create_program_account::<Collection>(
+            collection_info,
+            system_program,
+            signer_info,
+            &ore_api::ID,
+            &[COLLECTION, &mint_authority.to_bytes()],
+        )?;
+        let collection = collection_info.as_account_mut::<Collection>(&ore_api::ID)?;
+        collection.mint_authority = mint_authority;
+        collection.total_claimed = 0;
+        collection
+    } else {
+        collection_info.as_account_mut::<Collection>(&ore_api::ID)?
+    };
+
+    // Set collection. Perks already claimed are not changed.
+    collection.metadata_address = metadata_address;
+    collection.token_program = token_program;
+    collection.perk = perk;
+    collection.perk_bps = perk_bps;
+
+    Ok(())
+}
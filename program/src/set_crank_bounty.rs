@@ -0,0 +1,34 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the bounties paid to the callers of reset and close.
+pub fn process_set_crank_bounty(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetCrankBounty::try_from_bytes(data)?;
+    let reset_bounty = u64::from_le_bytes(args.reset_bounty);
+    let close_bounty = u64::from_le_bytes(args.close_bounty);
+
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate bounties.
+    if reset_bounty > MAX_CRANK_BOUNTY || close_bounty > MAX_CRANK_BOUNTY {
+        return Err(OreError::BountyTooLarge.into());
+    }
+
+    // Set crank bounties.
+    config.reset_bounty = reset_bounty;
+    config.close_bounty = close_bounty;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the minimum and maximum amounts a miner can deploy.
+pub fn process_set_deploy_limits(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetDeployLimits::try_from_bytes(data)?;
+    let min_deploy_amount = u64::from_le_bytes(args.min_deploy_amount);
+    let max_deploy_per_round = u64::from_le_bytes(args.max_deploy_per_round);
+    let max_deploy_per_square = u64::from_le_bytes(args.max_deploy_per_square);
+
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate limits. The minimum must fit within both maximums.
+    if (max_deploy_per_round > 0 && min_deploy_amount > max_deploy_per_round)
+        || (max_deploy_per_square > 0 && min_deploy_amount > max_deploy_per_square)
+    {
+        return Err(OreError::InvalidDeployLimits.into());
+    }
+
+    // Set deploy limits.
+    config.min_deploy_amount = min_deploy_amount;
+    config.max_deploy_per_round = max_deploy_per_round;
+    config.max_deploy_per_square = max_deploy_per_square;
+
+    Ok(())
+}
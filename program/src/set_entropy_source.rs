@@ -0,0 +1,29 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the entropy source for new rounds.
+pub fn process_set_entropy_source(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetEntropySource::try_from_bytes(data)?;
+    let entropy_source = EntropySource::from_u64(u64::from_le_bytes(args.entropy_source))?;
+    let randomness_authority = Pubkey::new_from_array(args.randomness_authority);
+
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Set entropy source.
+    config.entropy_source = entropy_source as u64;
+    config.randomness_authority = randomness_authority;
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the recipients of admin fees, and makes the fee split the fee collector.
+pub fn process_set_fee_split(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetFeeSplit::try_from_bytes(data)?;
+    let num_recipients = u64::from_le_bytes(args.num_recipients) as usize;
+    if num_recipients > MAX_FEE_RECIPIENTS {
+        return Err(OreError::InvalidFeeSplit.into());
+    }
+    let recipients = args
+        .addresses
+        .iter()
+        .zip(args.bps.iter())
+        .take(num_recipients)
+        .map(|(address, bps)| FeeRecipient {
+            address: Pubkey::new_from_array(*address),
+            bps: u64::from_le_bytes(*bps),
+        })
+        .collect::<Vec<FeeRecipient>>();
+
+    // Load accounts.
+    let [signer_info, config_info, fee_split_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    fee_split_info
+        .is_writable()?
+        .has_seeds(&[FEE_SPLIT], &ore_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate recipients.
+    if !FeeSplit::is_valid(&recipients) {
+        return Err(OreError::InvalidFeeSplit.into());
+    }
+
+    // Open fee split account.
+    let fee_split = if fee_split_info.data_is_empty() {
+        create_program_account::<FeeSplit>(
+            fee_split_info,
+            system_program,
+            signer_info,
+            &ore_api::ID,
+            &[FEE_SPLIT],
+        )?;
+        let fee_split = fee_split_info.as_account_mut::<FeeSplit>(&ore_api::ID)?;
+        fee_split.balance = 0;
+        fee_split.total_distributed = 0;
+        fee_split
+    } else {
+        fee_split_info.as_account_mut::<FeeSplit>(&ore_api::ID)?
+    };
+
+    // Set recipients. Fees accrued but not yet distributed are paid to the new recipients.
+    fee_split.num_recipients = recipients.len() as u64;
+    fee_split.recipients = [FeeRecipient::zeroed(); MAX_FEE_RECIPIENTS];
+    fee_split.recipients[..recipients.len()].copy_from_slice(&recipients);
+
+    // Collect admin fees in the fee split.
+    config.fee_collector = *fee_split_info.key;
+
+    Ok(())
+}
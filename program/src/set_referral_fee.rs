@@ -0,0 +1,32 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the share of admin fees on referred deployments paid to referrers.
+pub fn process_set_referral_fee(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetReferralFee::try_from_bytes(data)?;
+    let referral_fee_bps = u64::from_le_bytes(args.referral_fee_bps);
+
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate fee share.
+    if referral_fee_bps > DENOMINATOR_BPS {
+        return Err(OreError::InvalidBps.into());
+    }
+
+    // Set referral fee share.
+    config.referral_fee_bps = referral_fee_bps;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the extra reward weight multiplier for Seeker stakers.
+pub fn process_set_seeker_multiplier(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetSeekerMultiplier::try_from_bytes(data)?;
+    let seeker_multiplier_bps = u64::from_le_bytes(args.seeker_multiplier_bps);
+
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate multiplier.
+    if seeker_multiplier_bps > MAX_SEEKER_MULTIPLIER_BPS {
+        return Err(OreError::InvalidBps.into());
+    }
+
+    // Set Seeker multiplier.
+    // Existing Seeker stakers pick up the new multiplier on their next deposit or withdraw.
+    config.seeker_multiplier_bps = seeker_multiplier_bps;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the claim fee discount for miners who claim ORE straight into a stake account.
+pub fn process_set_stake_claim_discount(
+    accounts: &[AccountInfo<'_>],
+    data: &[u8],
+) -> ProgramResult {
+    // Parse data.
+    let args = SetStakeClaimDiscount::try_from_bytes(data)?;
+    let stake_claim_fee_discount_bps = u64::from_le_bytes(args.stake_claim_fee_discount_bps);
+
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate discount.
+    if stake_claim_fee_discount_bps > DENOMINATOR_BPS {
+        return Err(OreError::InvalidBps.into());
+    }
+
+    // Set claim fee discount.
+    config.stake_claim_fee_discount_bps = stake_claim_fee_discount_bps;
+
+    Ok(())
+}
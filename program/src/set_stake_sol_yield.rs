@@ -0,0 +1,34 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the share of admin fees and vault cuts paid to stakers as SOL yield.
+pub fn process_set_stake_sol_yield(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetStakeSolYield::try_from_bytes(data)?;
+    let admin_fee_bps = u64::from_le_bytes(args.admin_fee_bps);
+    let vault_bps = u64::from_le_bytes(args.vault_bps);
+
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate basis points.
+    if admin_fee_bps > DENOMINATOR_BPS || vault_bps > DENOMINATOR_BPS {
+        return Err(OreError::InvalidBps.into());
+    }
+
+    // Set SOL yield shares.
+    config.stake_admin_fee_bps = admin_fee_bps;
+    config.stake_vault_bps = vault_bps;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the cooldown between unstaking and withdrawing ORE.
+pub fn process_set_unstake_cooldown(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetUnstakeCooldown::try_from_bytes(data)?;
+    let unstake_cooldown = i64::from_le_bytes(args.unstake_cooldown);
+
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate cooldown.
+    if unstake_cooldown < 0 || unstake_cooldown > MAX_UNSTAKE_COOLDOWN {
+        return Err(OreError::CooldownTooLong.into());
+    }
+
+    // Set unstake cooldown.
+    config.unstake_cooldown = unstake_cooldown;
+
+    Ok(())
+}
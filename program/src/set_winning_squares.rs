@@ -0,0 +1,32 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the number of squares that win each new round.
+pub fn process_set_winning_squares(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetWinningSquares::try_from_bytes(data)?;
+    let winning_squares = u64::from_le_bytes(args.winning_squares);
+
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate number of winning squares.
+    if winning_squares == 0 || winning_squares > MAX_WINNING_SQUARES {
+        return Err(OreError::InvalidWinningSquares.into());
+    }
+
+    // Set number of winning squares. Rounds already opened are not changed.
+    config.winning_squares = winning_squares;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sets the maximum amount of SOL wrapped per call, and the reserve kept in the treasury.
+pub fn process_set_wrap_policy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetWrapPolicy::try_from_bytes(data)?;
+    let max_wrap = u64::from_le_bytes(args.max_wrap);
+    let treasury_reserve = u64::from_le_bytes(args.treasury_reserve);
+
+    // Load accounts.
+    let [signer_info, config_info, treasury_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&ore_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    let treasury = treasury_info.as_account::<Treasury>(&ore_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Validate wrap policy. Without a cap, or with a reserve above the treasury balance,
+    // nothing could be wrapped.
+    if max_wrap == 0 {
+        return Err(OreError::InvalidWrapCap.into());
+    }
+    if treasury_reserve > treasury.balance {
+        return Err(OreError::InvalidTreasuryReserve.into());
+    }
+
+    // Set wrap policy.
+    config.max_wrap = max_wrap;
+    config.treasury_reserve = treasury_reserve;
+
+    Ok(())
+}
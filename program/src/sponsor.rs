@@ -0,0 +1,78 @@
+use ore_api::prelude::*;
+use steel::*;
+
+/// Sponsors a bonus of SOL and ORE for the current or a future round.
+pub fn process_sponsor(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Sponsor::try_from_bytes(data)?;
+    let round_id = u64::from_le_bytes(args.round_id);
+    let amount_sol = u64::from_le_bytes(args.amount_sol);
+    let amount_ore = u64::from_le_bytes(args.amount_ore);
+    if amount_sol == 0 && amount_ore == 0 {
+        return Err(OreError::AmountTooSmall.into());
+    }
+
+    // Load accounts.
+    let [signer_info, board_info, bonus_info, mint_info, sender_info, treasury_info, treasury_tokens_info, system_program, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    board_info.as_account::<Board>(&ore_api::ID)?.assert_err(
+        |b| round_id >= b.round_id,
+        OreError::RoundAlreadyReset.into(),
+    )?;
+    bonus_info
+        .is_writable()?
+        .has_seeds(&[BONUS, &round_id.to_le_bytes()], &ore_api::ID)?;
+    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    treasury_info.has_address(&TREASURY_ADDRESS)?;
+    treasury_tokens_info
+        .is_writable()?
+        .as_associated_token_account(treasury_info.key, mint_info.key)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+
+    // Open bonus account.
+    let bonus = if bonus_info.data_is_empty() {
+        create_program_account::<Bonus>(
+            bonus_info,
+            system_program,
+            signer_info,
+            &ore_api::ID,
+            &[BONUS, &round_id.to_le_bytes()],
+        )?;
+        let bonus = bonus_info.as_account_mut::<Bonus>(&ore_api::ID)?;
+        bonus.round_id = round_id;
+        bonus.amount_sol = 0;
+        bonus.amount_ore = 0;
+        bonus.sponsor = *signer_info.key;
+        bonus
+    } else {
+        bonus_info.as_account_mut::<Bonus>(&ore_api::ID)?
+    };
+
+    // Escrow SOL in the bonus account.
+    if amount_sol > 0 {
+        bonus.amount_sol += amount_sol;
+        bonus_info.collect(amount_sol, signer_info)?;
+    }
+
+    // Escrow ORE in the treasury.
+    if amount_ore > 0 {
+        sender_info
+            .is_writable()?
+            .as_associated_token_account(signer_info.key, mint_info.key)?;
+        bonus.amount_ore += amount_ore;
+        transfer(
+            signer_info,
+            sender_info,
+            treasury_tokens_info,
+            token_program,
+            amount_ore,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use ore_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Transfers a miner account to a new authority.
+pub fn process_transfer_miner(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, new_authority_info, automation_info, config_info, miner_info, new_miner_info, season_info, season_miner_info, treasury_info, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    new_authority_info.is_signer()?;
+    automation_info
+        .is_empty()?
+        .has_seeds(&[AUTOMATION, &signer_info.key.to_bytes()], &ore_api::ID)?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    let miner = miner_info
+        .as_account_mut::<Miner>(&ore_api::ID)?
+        .assert_mut(|m| m.authority == *signer_info.key)?
+        .assert_mut_err(
+            |m| m.checkpoint_id == m.round_id,
+            OreError::NotCheckpointed.into(),
+        )?;
+    new_miner_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[MINER, &new_authority_info.key.to_bytes()], &ore_api::ID)?;
+    let season_id = config.season_id.to_le_bytes();
+    season_info.has_seeds(&[SEASON, &season_id], &ore_api::ID)?;
+    season_miner_info.has_seeds(
+        &[SEASON_MINER, &season_id, &signer_info.key.to_bytes()],
+        &ore_api::ID,
+    )?;
+    let treasury = treasury_info.as_account::<Treasury>(&ore_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Season stats are kept by authority, so a miner playing in a season cannot transfer until it ends.
+    if !season_info.data_is_empty() && !season_miner_info.data_is_empty() {
+        season_info
+            .as_account::<Season>(&ore_api::ID)?
+            .assert_err(|s| s.claimable_at > 0, OreError::SeasonInProgress.into())?;
+    }
+
+    // Checkpoint refined rewards.
+    miner.update_rewards(treasury);
+
+    // Open new miner account.
+    create_program_account::<Miner>(
+        new_miner_info,
+        system_program,
+        signer_info,
+        &ore_api::ID,
+        &[MINER, &new_authority_info.key.to_bytes()],
+    )?;
+    let new_miner = new_miner_info.as_account_mut::<Miner>(&ore_api::ID)?;
+
+    // Copy balances, reward factors, and lifetime stats.
+    *new_miner = *miner;
+    new_miner.authority = *new_authority_info.key;
+
+    // Claim delegation is set by the prior authority, so it does not carry over.
+    new_miner.claim_delegate = Pubkey::default();
+    new_miner.payout_recipient = Pubkey::default();
+
+    // Move SOL held for rewards and checkpoint fees.
+    let amount = miner.rewards_sol + miner.checkpoint_fee;
+    if amount > 0 {
+        miner_info.send(amount, new_miner_info);
+    }
+
+    sol_log(&format!("Transferring miner to {}", new_authority_info.key).as_str());
+
+    // Close the old miner account.
+    miner_info.close(signer_info)?;
+
+    Ok(())
+}
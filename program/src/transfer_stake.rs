@@ -0,0 +1,114 @@
+use ore_api::prelude::*;
+use solana_program::log::sol_log;
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+/// Transfers a stake account to a new authority.
+pub fn process_transfer_stake(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, new_authority_info, mint_info, stake_info, stake_tokens_info, new_stake_info, new_stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    new_authority_info.is_signer()?;
+    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    let stake = stake_info
+        .as_account_mut::<Stake>(&ore_api::ID)?
+        .assert_mut(|s| s.authority == *signer_info.key)?;
+    let stake_tokens = stake_tokens_info
+        .is_writable()?
+        .as_associated_token_account(stake_info.key, mint_info.key)?;
+    new_stake_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[STAKE, &new_authority_info.key.to_bytes()], &ore_api::ID)?;
+    new_stake_tokens_info.is_writable()?;
+    let treasury = treasury_info.as_account::<Treasury>(&ore_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // Checkpoint rewards.
+    stake.update_rewards(treasury);
+
+    // Open new stake account.
+    create_program_account::<Stake>(
+        new_stake_info,
+        system_program,
+        signer_info,
+        &ore_api::ID,
+        &[STAKE, &new_authority_info.key.to_bytes()],
+    )?;
+    let new_stake = new_stake_info.as_account_mut::<Stake>(&ore_api::ID)?;
+
+    // Copy balances, reward factors, locks, and lifetime stats.
+    // The effective weight is unchanged, so the treasury total does not need to be updated.
+    *new_stake = *stake;
+    new_stake.authority = *new_authority_info.key;
+
+    // Create new stake tokens account.
+    if new_stake_tokens_info.data_is_empty() {
+        create_associated_token_account(
+            signer_info,
+            new_stake_info,
+            new_stake_tokens_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        new_stake_tokens_info.as_associated_token_account(new_stake_info.key, mint_info.key)?;
+    }
+
+    // Move staked ORE to the new stake tokens account.
+    let amount = stake_tokens.amount();
+    transfer_signed(
+        stake_info,
+        stake_tokens_info,
+        new_stake_tokens_info,
+        token_program,
+        amount,
+        &[STAKE, &signer_info.key.to_bytes()],
+    )?;
+
+    sol_log(
+        &format!(
+            "Transferring {} ORE to {}",
+            amount_to_ui_amount(amount, TOKEN_DECIMALS),
+            new_authority_info.key
+        )
+        .as_str(),
+    );
+
+    // Close the old stake tokens account.
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            &spl_token::ID,
+            stake_tokens_info.key,
+            signer_info.key,
+            stake_info.key,
+            &[],
+        )?,
+        &[
+            stake_tokens_info.clone(),
+            signer_info.clone(),
+            stake_info.clone(),
+            token_program.clone(),
+        ],
+        &ore_api::ID,
+        &[STAKE, &signer_info.key.to_bytes()],
+    )?;
+
+    // Close the old stake account.
+    stake_info.close(signer_info)?;
+
+    // Safety check.
+    let new_stake_tokens =
+        new_stake_tokens_info.as_associated_token_account(new_stake_info.key, mint_info.key)?;
+    assert!(new_stake_tokens.amount() >= new_stake.balance + new_stake.pending_withdrawal);
+
+    Ok(())
+}
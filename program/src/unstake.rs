@@ -0,0 +1,39 @@
+use ore_api::prelude::*;
+use solana_program::log::sol_log;
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+/// Moves staked ORE into a pending withdrawal, to be withdrawn after the cooldown.
+pub fn process_unstake(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Unstake::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, stake_info, treasury_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&ore_api::ID)?;
+    let stake = stake_info
+        .as_account_mut::<Stake>(&ore_api::ID)?
+        .assert_mut(|s| s.authority == *signer_info.key)?
+        .assert_mut_err(|s| !s.is_locked(&clock), OreError::StakeLocked.into())?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+
+    // Unstake from stake account.
+    let amount = stake.unstake(amount, &clock, config, treasury);
+
+    // Log unstake.
+    sol_log(
+        &format!(
+            "Unstaking {} ORE, withdrawable at {}",
+            amount_to_ui_amount(amount, TOKEN_DECIMALS),
+            stake.withdrawable_at
+        )
+        .as_str(),
+    );
+
+    Ok(())
+}
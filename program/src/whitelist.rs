@@ -1,6 +0,0 @@
-use solana_program::pubkey;
-use steel::*;
-
-#[allow(unused)]
-pub const AUTHORIZED_ACCOUNTS: [Pubkey; 1] =
-    [pubkey!("pqspJ298ryBjazPAr95J9sULCVpZe3HbZTWkbC1zrkS")];
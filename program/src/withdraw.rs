@@ -3,7 +3,7 @@ use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
-/// Withdraws ORE from the staking contract.
+/// Withdraws unstaked ORE from the staking contract, once the cooldown has elapsed.
 pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = Withdraw::try_from_bytes(data)?;
@@ -23,9 +23,13 @@ pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
         .as_associated_token_account(&signer_info.key, &mint_info.key)?;
     let stake = stake_info
         .as_account_mut::<Stake>(&ore_api::ID)?
-        .assert_mut(|s| s.authority == *signer_info.key)?;
+        .assert_mut(|s| s.authority == *signer_info.key)?
+        .assert_mut_err(
+            |s| !s.is_cooling_down(&clock),
+            OreError::CooldownNotElapsed.into(),
+        )?;
     stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
-    let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
+    treasury_info.as_account::<Treasury>(&ore_api::ID)?;
     system_program.is_program(&system_program::ID)?;
     token_program.is_program(&spl_token::ID)?;
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
@@ -43,8 +47,8 @@ pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
         )?;
     }
 
-    // Deposit into stake account.
-    let amount = stake.withdraw(amount, &clock, treasury);
+    // Withdraw pending ORE from stake account.
+    let amount = stake.withdraw(amount, &clock);
 
     // Transfer ORE to recipient.
     transfer_signed(
@@ -68,7 +72,7 @@ pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
     // Safety check.
     let stake_tokens =
         stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
-    assert!(stake_tokens.amount() >= stake.balance);
+    assert!(stake_tokens.amount() >= stake.balance + stake.pending_withdrawal);
 
     Ok(())
 }
@@ -3,14 +3,20 @@ use solana_program::rent::Rent;
 use steel::*;
 
 /// Send SOL from the treasury to the WSOL account.
-pub fn process_wrap(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+pub fn process_wrap(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Wrap::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
     // Load accounts.
-    let [signer_info, config_info, treasury_info, treasury_sol_info, system_program] = accounts
+    let [signer_info, board_info, config_info, treasury_info, treasury_sol_info, system_program, ore_program] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
-    config_info
+    board_info.as_account::<Board>(&ore_api::ID)?;
+    let config = config_info
         .as_account::<Config>(&ore_api::ID)?
         .assert(|c| c.bury_authority == *signer_info.key)?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&ore_api::ID)?;
@@ -18,10 +24,35 @@ pub fn process_wrap(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult
         .is_writable()?
         .as_associated_token_account(treasury_info.key, &SOL_MINT)?;
     system_program.is_program(&system_program::ID)?;
+    ore_program.is_program(&ore_api::ID)?;
+
+    // Wrap SOL.
+    wrap_sol(
+        config,
+        treasury,
+        board_info,
+        treasury_info,
+        treasury_sol_info,
+        ore_program,
+        amount,
+    )?;
 
+    Ok(())
+}
+
+/// Sends up to the requested amount of SOL from the treasury to the WSOL account, bounded by the
+/// per-call cap and the treasury reserve. Returns the amount wrapped.
+pub fn wrap_sol<'a>(
+    config: &Config,
+    treasury: &mut Treasury,
+    board_info: &AccountInfo<'a>,
+    treasury_info: &AccountInfo<'a>,
+    treasury_sol_info: &AccountInfo<'a>,
+    ore_program: &AccountInfo<'a>,
+    requested: u64,
+) -> Result<u64, ProgramError> {
     // Get amount
-    let one_sol = 1_000_000_000;
-    let amount = (one_sol * 10).min(treasury.balance);
+    let amount = config.wrap_amount(treasury.balance, requested);
 
     // Send SOL to the WSOL account.
     treasury_info.send(amount, treasury_sol_info);
@@ -36,5 +67,18 @@ pub fn process_wrap(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult
     // Update treasury.
     treasury.balance -= amount;
 
-    Ok(())
+    // Emit event.
+    program_log(
+        &[board_info.clone(), ore_program.clone()],
+        WrapEvent {
+            disc: 5,
+            requested,
+            amount,
+            treasury_balance: treasury.balance,
+            ts: Clock::get()?.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(amount)
 }
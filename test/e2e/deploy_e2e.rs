@@ -1,4 +1,5 @@
 use anyhow::Result;
+use bytemuck::Zeroable;
 use ore_api::prelude::*;
 use ore_integration_tests::*;
 use solana_sdk::{
@@ -55,12 +56,30 @@ async fn test_query_available_blocks() -> Result<()> {
     // Get available blocks
     let available = get_available_blocks(&round, 1.0);
     println!("Available blocks (< 1 SOL): {:?}", available);
-    println!("Total available: {}/{}", available.len(), 25);
+    println!(
+        "Total available: {}/{}",
+        available.len(),
+        round.num_squares()
+    );
 
     // Assertions
     assert_eq!(board.round_id, round.id, "Round ID mismatch");
-    assert_eq!(round.deployed.len(), 25, "Should have 25 squares");
-    assert_eq!(round.count.len(), 25, "Should have 25 count entries");
+    assert_eq!(
+        round.deployed.len(),
+        MAX_SQUARES,
+        "Should have {} squares",
+        MAX_SQUARES
+    );
+    assert_eq!(
+        round.count.len(),
+        MAX_SQUARES,
+        "Should have {} count entries",
+        MAX_SQUARES
+    );
+    assert!(
+        round.num_squares() <= MAX_SQUARES,
+        "Board size exceeds maximum"
+    );
 
     println!("\n✅ Test passed!\n");
     Ok(())
@@ -114,7 +133,7 @@ async fn test_full_deployment_flow() -> Result<()> {
     // Validate instruction structure
     let program_id = Pubkey::from_str(ORE_PROGRAM_ID)?;
     assert_eq!(deploy_ix.program_id, program_id, "Wrong program ID");
-    assert_eq!(deploy_ix.accounts.len(), 7, "Should have 7 accounts");
+    assert_eq!(deploy_ix.accounts.len(), 11, "Should have 11 accounts");
     assert!(!deploy_ix.data.is_empty(), "Instruction data should not be empty");
     println!("✅ Instruction validation passed");
     println!("   - Program ID: {}", deploy_ix.program_id);
@@ -150,17 +169,12 @@ async fn test_deployment_with_state_validation() -> Result<()> {
     // Simulate initial round state
     let initial_round = Round {
         id: 1,
-        deployed: [0; 25],
-        slot_hash: [0; 32],
-        count: [0; 25],
         expires_at: 1000,
         motherlode: 10 * LAMPORTS_PER_SOL,
         rent_payer: miner.pubkey(),
-        top_miner: Pubkey::default(),
-        top_miner_reward: 0,
-        total_deployed: 0,
-        total_vaulted: 0,
-        total_winnings: 0,
+        winning_squares: 1,
+        squares: 25,
+        ..Round::zeroed()
     };
 
     println!("📋 Initial Round State:");
@@ -236,7 +250,7 @@ async fn test_multi_block_deployment() -> Result<()> {
         );
 
         // Verify instruction
-        assert_eq!(ix.accounts.len(), 7, "Invalid instruction structure");
+        assert_eq!(ix.accounts.len(), 11, "Invalid instruction structure");
         assert!(!ix.data.is_empty(), "Empty instruction data");
 
         println!("  ✅ Instruction created and validated");
@@ -314,8 +328,8 @@ async fn test_miner_state_after_deployment() -> Result<()> {
     // Simulate miner state before deployment
     let mut miner_state = Miner {
         authority: miner_keypair.pubkey(),
-        deployed: [0; 25],
-        cumulative: [0; 25],
+        deployed: [0; MAX_SQUARES],
+        cumulative: [0; MAX_SQUARES],
         checkpoint_fee: 0,
         checkpoint_id: 0,
         last_claim_ore_at: 0,
@@ -327,6 +341,10 @@ async fn test_miner_state_after_deployment() -> Result<()> {
         round_id,
         lifetime_rewards_ore: 0,
         lifetime_rewards_sol: 0,
+        claim_delegate: Pubkey::default(),
+        payout_recipient: Pubkey::default(),
+        referrer: Pubkey::default(),
+        claim_fee_discount_bps: 0,
     };
 
     println!("📊 Miner State Before Deployment:");
@@ -394,6 +412,7 @@ fn test_block_bitmask_encoding() -> Result<()> {
         (vec![12], "Center"),
         (vec![0, 6, 12, 18, 24], "Cross pattern"),
         (vec![5, 10, 15, 20], "Diagonal"),
+        (vec![0, 31, 32, 63], "Large board"),
     ];
 
     for (blocks, description) in test_cases {
@@ -401,13 +420,13 @@ fn test_block_bitmask_encoding() -> Result<()> {
         println!("  Blocks: {:?}", blocks);
 
         // Create squares array
-        let mut squares = [false; 25];
+        let mut squares = [false; MAX_SQUARES];
         for &block in &blocks {
             squares[block] = true;
         }
 
         // Verify bitmask
-        let mut mask: u32 = 0;
+        let mut mask: u64 = 0;
         for (i, &square) in squares.iter().enumerate() {
             if square {
                 mask |= 1 << i;
@@ -416,14 +435,14 @@ fn test_block_bitmask_encoding() -> Result<()> {
 
         // Decode back
         let mut decoded_blocks = Vec::new();
-        for i in 0..25 {
+        for i in 0..MAX_SQUARES {
             if (mask & (1 << i)) != 0 {
                 decoded_blocks.push(i);
             }
         }
 
         assert_eq!(blocks, decoded_blocks, "Bitmask encode/decode mismatch");
-        println!("  ✅ Mask: 0x{:08x} (correct)\n", mask);
+        println!("  ✅ Mask: 0x{:016x} (correct)\n", mask);
     }
 
     println!("✅ All bitmask encodings correct!\n");
@@ -458,7 +477,7 @@ fn test_deploy_instruction_creation() -> Result<()> {
 
     // Verify instruction
     assert_eq!(ix.program_id, program_id, "Wrong program ID");
-    assert_eq!(ix.accounts.len(), 7, "Should have 7 accounts");
+    assert_eq!(ix.accounts.len(), 11, "Should have 11 accounts");
     assert!(!ix.data.is_empty(), "Instruction data should not be empty");
 
     println!("✅ Instruction created successfully");
@@ -551,7 +570,7 @@ async fn test_fetch_available_blocks_flow() -> Result<()> {
     // Simulate a round with mixed deployment status
     let round = Round {
         id: 100,
-        deployed: [
+        deployed: pad_squares(&[
             // First 10 blocks: low deployment (available)
             10_000_000, 20_000_000, 30_000_000, 40_000_000, 50_000_000,
             60_000_000, 70_000_000, 80_000_000, 90_000_000, 100_000_000,
@@ -560,17 +579,14 @@ async fn test_fetch_available_blocks_flow() -> Result<()> {
             1_200_000_000, 2_200_000_000, 1_900_000_000, 2_100_000_000, 1_600_000_000,
             // Last 5 blocks: low deployment (available)
             50_000_000, 60_000_000, 70_000_000, 80_000_000, 90_000_000,
-        ],
-        slot_hash: [0; 32],
-        count: [1; 25],
+        ]),
+        count: pad_squares(&[1; 25]),
         expires_at: 1000,
         motherlode: 10 * LAMPORTS_PER_SOL,
         rent_payer: miner.pubkey(),
-        top_miner: Pubkey::default(),
-        top_miner_reward: 0,
-        total_deployed: 0,
-        total_vaulted: 0,
-        total_winnings: 0,
+        winning_squares: 1,
+        squares: 25,
+        ..Round::zeroed()
     };
 
     println!("📋 Round Configuration:");
@@ -667,17 +683,13 @@ async fn test_multi_block_deployment_flow() -> Result<()> {
     // Step 1: Simulate fetching available blocks
     let round = Round {
         id: 200,
-        deployed: [50_000_000; 25], // All blocks available (0.05 SOL each)
-        slot_hash: [0; 32],
-        count: [0; 25],
+        deployed: pad_squares(&[50_000_000; 25]), // All blocks available (0.05 SOL each)
         expires_at: 1000,
         motherlode: 10 * LAMPORTS_PER_SOL,
         rent_payer: miner.pubkey(),
-        top_miner: Pubkey::default(),
-        top_miner_reward: 0,
-        total_deployed: 0,
-        total_vaulted: 0,
-        total_winnings: 0,
+        winning_squares: 1,
+        squares: 25,
+        ..Round::zeroed()
     };
 
     let available = get_available_blocks(&round, 1.0);
@@ -708,7 +720,7 @@ async fn test_multi_block_deployment_flow() -> Result<()> {
             &[block],
         );
         
-        assert_eq!(ix.accounts.len(), 7, "Invalid instruction for block {}", block);
+        assert_eq!(ix.accounts.len(), 11, "Invalid instruction for block {}", block);
         assert!(!ix.data.is_empty(), "Empty instruction data for block {}", block);
         
         instructions.push((block, ix));
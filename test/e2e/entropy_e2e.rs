@@ -0,0 +1,341 @@
+use anyhow::Result;
+use bytemuck::{Pod, Zeroable};
+use ore_api::prelude::*;
+use ore_integration_tests::*;
+use solana_program::{
+    hash::Hash, program_option::COption, program_pack::Pack, slot_hashes::SlotHashes,
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    instruction::{Instruction, InstructionError},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use steel::Discriminator;
+
+/// The end slot of the round under test.
+const END_SLOT: u64 = 1_000;
+
+/// The hash of the end slot, as recorded in the slot hashes sysvar.
+const END_SLOT_HASH: [u8; 32] = [7; 32];
+
+#[tokio::test]
+async fn test_commit_reveal_with_local_authority() -> Result<()> {
+    println!("\n🎲 Test: Commit-Reveal Entropy (Local Authority)");
+    println!("══════════════════════════════════════════════════════════\n");
+
+    let authority = Keypair::new();
+    let round_id = 42u64;
+
+    // Set up test context and fund the randomness authority.
+    let mut context = setup_test_context().await;
+    fund_account(&mut context, authority.pubkey(), LAMPORTS_PER_SOL).await;
+    let balance = context.banks_client.get_balance(authority.pubkey()).await?;
+    assert_eq!(
+        balance, LAMPORTS_PER_SOL,
+        "Authority balance mismatch after funding"
+    );
+
+    // Derive the secret and commitment.
+    let secret = derive_entropy_secret(&authority, round_id);
+    assert_eq!(
+        secret,
+        derive_entropy_secret(&authority, round_id),
+        "Secret derivation should be deterministic"
+    );
+    assert_ne!(
+        secret,
+        derive_entropy_secret(&authority, round_id + 1),
+        "Secrets should differ between rounds"
+    );
+    let commit = CommitRevealEntropy::commitment(&secret);
+    println!("✅ Commitment: {:?}", commit);
+
+    // Build the commit instruction.
+    let commit_ix = ore_api::sdk::commit(authority.pubkey(), round_id, commit);
+    assert_eq!(commit_ix.accounts.len(), 4, "Should have 4 accounts");
+    assert!(commit_ix.accounts[0].is_signer, "Authority should sign");
+    assert_eq!(commit_ix.accounts[3].pubkey, round_pda(round_id).0);
+    assert_eq!(&commit_ix.data[1..], &commit, "Commit data mismatch");
+
+    // Build the reset instruction with the reveal.
+    let reset_ix = ore_api::sdk::reset(
+        authority.pubkey(),
+        authority.pubkey(),
+        round_id,
+        0,
+        authority.pubkey(),
+        Pubkey::default(),
+        secret,
+    );
+    assert_eq!(&reset_ix.data[1..], &secret, "Reveal data mismatch");
+
+    // Verify the reveal seeds the round.
+    let slot_hash = [7; 32];
+    let entropy = CommitRevealEntropy {
+        commit,
+        reveal: secret,
+    };
+    let seed = entropy
+        .seed(&slot_hash)
+        .expect("Reveal should match commitment");
+    assert_ne!(seed, slot_hash, "Seed should mix in the reveal");
+
+    // Verify a reveal from a different authority is rejected.
+    let other = Keypair::new();
+    let entropy = CommitRevealEntropy {
+        commit,
+        reveal: derive_entropy_secret(&other, round_id),
+    };
+    assert!(
+        entropy.seed(&slot_hash).is_none(),
+        "Foreign reveal should be rejected"
+    );
+
+    println!("\n✅ Commit-reveal flow validated!\n");
+
+    Ok(())
+}
+
+#[test]
+fn test_slot_hash_entropy_seed() -> Result<()> {
+    let slot_hash = [9; 32];
+    let seed = SlotHashEntropy.seed(&slot_hash).unwrap();
+    assert_eq!(
+        seed, slot_hash,
+        "Slot hash entropy should pass through the slot hash"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_commit_reveal_reset_with_valid_reveal() -> Result<()> {
+    let authority = Keypair::new();
+    let round_id = 42u64;
+    let secret = derive_entropy_secret(&authority, round_id);
+    let commit = CommitRevealEntropy::commitment(&secret);
+    let mut context = setup_commit_reveal_round(&authority, round_id).await;
+
+    // Commit before the round ends.
+    let commit_ix = ore_api::sdk::commit(authority.pubkey(), round_id, commit);
+    process_instruction(&mut context, commit_ix, &[&authority]).await?;
+    let round = get_round(&mut context, round_id).await?;
+    assert_eq!(
+        round.entropy_commit, commit,
+        "Commitment should be recorded"
+    );
+
+    // Reset with the reveal.
+    warp_past_end_slot(&mut context, INTERMISSION_SLOTS).await?;
+    let reset_ix = reset_instruction(&context, &authority, round_id, secret);
+    process_instruction(&mut context, reset_ix, &[]).await?;
+
+    // Verify the round was seeded with the reveal mixed into the slot hash.
+    let round = get_round(&mut context, round_id).await?;
+    let entropy = CommitRevealEntropy {
+        commit,
+        reveal: secret,
+    };
+    assert_eq!(round.entropy_reveal, secret, "Reveal should be recorded");
+    assert_eq!(round.sample_slot, END_SLOT, "End slot should be sampled");
+    assert_eq!(round.slot_hash, entropy.seed(&END_SLOT_HASH).unwrap());
+    let board = get_board(&mut context).await?;
+    assert_eq!(board.round_id, round_id + 1, "Board should advance");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_commit_reveal_reset_with_invalid_reveal() -> Result<()> {
+    let authority = Keypair::new();
+    let round_id = 42u64;
+    let secret = derive_entropy_secret(&authority, round_id);
+    let commit = CommitRevealEntropy::commitment(&secret);
+    let mut context = setup_commit_reveal_round(&authority, round_id).await;
+    let commit_ix = ore_api::sdk::commit(authority.pubkey(), round_id, commit);
+    process_instruction(&mut context, commit_ix, &[&authority]).await?;
+
+    // A reveal which does not match the commitment is rejected within the reveal window.
+    warp_past_end_slot(&mut context, INTERMISSION_SLOTS).await?;
+    let reset_ix = reset_instruction(&context, &authority, round_id, [1; 32]);
+    let err = process_instruction(&mut context, reset_ix, &[])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(OreError::InvalidReveal as u32)
+        ),
+        "Invalid reveal should be rejected"
+    );
+    let board = get_board(&mut context).await?;
+    assert_eq!(board.round_id, round_id, "Board should not advance");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_commit_reveal_reset_after_reveal_window() -> Result<()> {
+    let authority = Keypair::new();
+    let round_id = 42u64;
+    let secret = derive_entropy_secret(&authority, round_id);
+    let commit = CommitRevealEntropy::commitment(&secret);
+    let mut context = setup_commit_reveal_round(&authority, round_id).await;
+    let commit_ix = ore_api::sdk::commit(authority.pubkey(), round_id, commit);
+    process_instruction(&mut context, commit_ix, &[&authority]).await?;
+
+    // The randomness authority never reveals, so the round is refunded.
+    warp_past_end_slot(&mut context, REVEAL_WINDOW_SLOTS).await?;
+    let reset_ix = reset_instruction(&context, &authority, round_id, [0; 32]);
+    process_instruction(&mut context, reset_ix, &[]).await?;
+
+    let round = get_round(&mut context, round_id).await?;
+    assert_eq!(
+        round.entropy_reveal, [0; 32],
+        "No reveal should be recorded"
+    );
+    assert!(round.rng().is_none(), "Round should be refunded");
+    assert_eq!(round.total_deployed, 0, "Deployed SOL should be refunded");
+    let board = get_board(&mut context).await?;
+    assert_eq!(board.round_id, round_id + 1, "Board should advance");
+
+    Ok(())
+}
+
+/// Starts the ore program with a commit-reveal round in progress.
+async fn setup_commit_reveal_round(authority: &Keypair, round_id: u64) -> ProgramTestContext {
+    let mut program_test =
+        ProgramTest::new("ore", ore_api::ID, processor!(ore::process_instruction));
+    program_test.prefer_bpf(false);
+
+    let mut board = Board::zeroed();
+    board.round_id = round_id;
+    board.end_slot = END_SLOT;
+    program_test.add_account(board_pda().0, program_account(&board));
+
+    let mut config = Config::zeroed();
+    config.admin = authority.pubkey();
+    config.fee_collector = authority.pubkey();
+    config.randomness_authority = authority.pubkey();
+    config.entropy_source = EntropySource::CommitReveal as u64;
+    program_test.add_account(config_pda().0, program_account(&config));
+
+    let mut round = Round::zeroed();
+    round.id = round_id;
+    round.expires_at = u64::MAX;
+    round.entropy_source = EntropySource::CommitReveal as u64;
+    round.winning_squares = 1;
+    program_test.add_account(round_pda(round_id).0, program_account(&round));
+    program_test.add_account(TREASURY_ADDRESS, program_account(&Treasury::zeroed()));
+
+    let mint = spl_token::state::Mint {
+        mint_authority: COption::Some(TREASURY_ADDRESS),
+        supply: 0,
+        decimals: TOKEN_DECIMALS,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    program_test.add_account(MINT_ADDRESS, token_account(mint));
+    let treasury_tokens = spl_token::state::Account {
+        mint: MINT_ADDRESS,
+        owner: TREASURY_ADDRESS,
+        state: spl_token::state::AccountState::Initialized,
+        ..Default::default()
+    };
+    program_test.add_account(treasury_tokens_address(), token_account(treasury_tokens));
+
+    program_test.start_with_context().await
+}
+
+/// Warps past the round's end slot, keeping the end slot hash in the slot hashes sysvar.
+async fn warp_past_end_slot(context: &mut ProgramTestContext, slots: u64) -> Result<()> {
+    context.warp_to_slot(END_SLOT + slots)?;
+    context.set_sysvar(&SlotHashes::new(&[(
+        END_SLOT,
+        Hash::new_from_array(END_SLOT_HASH),
+    )]));
+    Ok(())
+}
+
+fn reset_instruction(
+    context: &ProgramTestContext,
+    authority: &Keypair,
+    round_id: u64,
+    reveal: [u8; 32],
+) -> Instruction {
+    ore_api::sdk::reset(
+        context.payer.pubkey(),
+        authority.pubkey(),
+        round_id,
+        0,
+        context.payer.pubkey(),
+        Pubkey::default(),
+        reveal,
+    )
+}
+
+async fn process_instruction(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn get_board(context: &mut ProgramTestContext) -> Result<Board> {
+    let account = context
+        .banks_client
+        .get_account(board_pda().0)
+        .await?
+        .expect("Board should exist");
+    parse_board(&account)
+}
+
+async fn get_round(context: &mut ProgramTestContext, round_id: u64) -> Result<Round> {
+    let account = context
+        .banks_client
+        .get_account(round_pda(round_id).0)
+        .await?
+        .expect("Round should exist");
+    parse_round(&account)
+}
+
+fn program_account<T: Discriminator + Pod>(state: &T) -> Account {
+    let mut data = vec![0; 8];
+    data[0] = T::discriminator();
+    data.extend_from_slice(bytemuck::bytes_of(state));
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: ore_api::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn token_account<T: Pack>(state: T) -> Account {
+    let mut data = vec![0; T::LEN];
+    state.pack_into_slice(&mut data);
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: spl_token::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
@@ -0,0 +1,140 @@
+use anyhow::Result;
+use bytemuck::{Pod, Zeroable};
+use ore_api::prelude::*;
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    instruction::{Instruction, InstructionError},
+    native_token::LAMPORTS_PER_SOL,
+    rent::Rent,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use steel::{AccountDeserialize, Discriminator};
+
+/// The treasury balance under test.
+const TREASURY_BALANCE: u64 = 100 * LAMPORTS_PER_SOL;
+
+#[tokio::test]
+async fn test_set_wrap_policy() -> Result<()> {
+    let admin = Keypair::new();
+    let mut context = setup_treasury(&admin).await;
+
+    // A non-zero cap and a reserve within the treasury balance are accepted.
+    let ix = ore_api::sdk::set_wrap_policy(admin.pubkey(), LAMPORTS_PER_SOL, TREASURY_BALANCE);
+    process_instruction(&mut context, ix, &[&admin]).await?;
+    let config = get_config(&mut context).await?;
+    assert_eq!(config.max_wrap, LAMPORTS_PER_SOL, "Cap should be set");
+    assert_eq!(
+        config.treasury_reserve, TREASURY_BALANCE,
+        "Reserve should be set"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_wrap_policy_rejects_zero_cap() -> Result<()> {
+    let admin = Keypair::new();
+    let mut context = setup_treasury(&admin).await;
+
+    // Without a cap, nothing could be wrapped.
+    let ix = ore_api::sdk::set_wrap_policy(admin.pubkey(), 0, 0);
+    let err = process_instruction(&mut context, ix, &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(OreError::InvalidWrapCap as u32)
+        ),
+        "Zero cap should be rejected"
+    );
+    let config = get_config(&mut context).await?;
+    assert_eq!(config.max_wrap, DEFAULT_MAX_WRAP, "Cap should not change");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_wrap_policy_rejects_reserve_above_balance() -> Result<()> {
+    let admin = Keypair::new();
+    let mut context = setup_treasury(&admin).await;
+
+    // A reserve above the treasury balance would leave nothing to wrap.
+    let ix = ore_api::sdk::set_wrap_policy(admin.pubkey(), LAMPORTS_PER_SOL, TREASURY_BALANCE + 1);
+    let err = process_instruction(&mut context, ix, &[&admin])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(OreError::InvalidTreasuryReserve as u32)
+        ),
+        "Reserve above the treasury balance should be rejected"
+    );
+    let config = get_config(&mut context).await?;
+    assert_eq!(config.treasury_reserve, 0, "Reserve should not change");
+
+    Ok(())
+}
+
+/// Starts the ore program with a funded treasury.
+async fn setup_treasury(admin: &Keypair) -> ProgramTestContext {
+    let mut program_test =
+        ProgramTest::new("ore", ore_api::ID, processor!(ore::process_instruction));
+    program_test.prefer_bpf(false);
+
+    let mut config = Config::zeroed();
+    config.admin = admin.pubkey();
+    config.max_wrap = DEFAULT_MAX_WRAP;
+    program_test.add_account(config_pda().0, program_account(&config));
+
+    let mut treasury = Treasury::zeroed();
+    treasury.balance = TREASURY_BALANCE;
+    program_test.add_account(TREASURY_ADDRESS, program_account(&treasury));
+
+    program_test.start_with_context().await
+}
+
+async fn process_instruction(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn get_config(context: &mut ProgramTestContext) -> Result<Config> {
+    let account = context
+        .banks_client
+        .get_account(config_pda().0)
+        .await?
+        .expect("Config should exist");
+    Ok(*Config::try_from_bytes(&account.data)?)
+}
+
+fn program_account<T: Discriminator + Pod>(state: &T) -> Account {
+    let mut data = vec![0; 8];
+    data[0] = T::discriminator();
+    data.extend_from_slice(bytemuck::bytes_of(state));
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: ore_api::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
@@ -5,7 +5,7 @@ use solana_sdk::{
     account::{Account, AccountSharedData},
     native_token::lamports_to_sol,
     pubkey::Pubkey,
-    signature::Signature,
+    signature::{Keypair, Signature},
     signer::Signer,
     system_instruction,
     transaction::Transaction,
@@ -76,7 +76,13 @@ pub fn display_board_state(board: &Board, round: &Round, slot: u64) {
     println!("┌──────┬─────────────┬───────┐");
     println!("│ Block│ Deployed    │ Count │");
     println!("├──────┼─────────────┼───────┤");
-    for (i, (&deployed, &count)) in round.deployed.iter().zip(&round.count).enumerate() {
+    for (i, (&deployed, &count)) in round
+        .deployed
+        .iter()
+        .zip(&round.count)
+        .take(round.num_squares())
+        .enumerate()
+    {
         let status = if deployed == 0 {
             "AVAILABLE  ".to_string()
         } else {
@@ -126,6 +132,7 @@ pub fn get_available_blocks(round: &Round, threshold_sol: f64) -> Vec<usize> {
     round
         .deployed
         .iter()
+        .take(round.num_squares())
         .enumerate()
         .filter(|(_, &deployed)| deployed < threshold_lamports)
         .map(|(i, _)| i)
@@ -140,15 +147,36 @@ pub fn create_deploy_instruction(
     round_id: u64,
     blocks: &[usize],
 ) -> solana_sdk::instruction::Instruction {
-    // Create squares array (25 bools)
-    let mut squares = [false; 25];
+    // Create squares array
+    let mut squares = [false; MAX_SQUARES];
     for &block in blocks {
-        if block < 25 {
+        if block < MAX_SQUARES {
             squares[block] = true;
         }
     }
 
-    ore_api::sdk::deploy(signer, authority, amount_lamports, round_id, squares)
+    ore_api::sdk::deploy(
+        signer,
+        authority,
+        amount_lamports,
+        round_id,
+        0,
+        &squares,
+        Pubkey::default(),
+    )
+}
+
+/// Pad per-square values to the full width of the square arrays
+pub fn pad_squares(values: &[u64]) -> [u64; MAX_SQUARES] {
+    let mut squares = [0; MAX_SQUARES];
+    squares[..values.len()].copy_from_slice(values);
+    squares
+}
+
+/// Derive a round's entropy secret from a signature by the local randomness authority
+pub fn derive_entropy_secret(authority: &Keypair, round_id: u64) -> [u8; 32] {
+    let signature = authority.sign_message(&round_id.to_le_bytes());
+    solana_program::keccak::hashv(&[signature.as_ref()]).0
 }
 
 /// Verify deployment in round state